@@ -1,7 +1,64 @@
-use std::io::prelude::*;
+use std::io::{self, prelude::*};
 
-pub fn main_impl(input: impl BufRead, mut output: impl Write) {
-    writeln!(output, "Hello {name}!", name = input_name(input)).expect("An output error occured");
+use solution::Solution;
+
+/// The solution to Problem A1.
+#[derive(Clone, Copy, Debug)]
+pub struct A1;
+
+impl Solution for A1 {
+    const NAME: &'static str = "A1";
+    const DESCRIPTION: &'static str = env!("CARGO_PKG_DESCRIPTION");
+
+    type Input = String;
+    type Output = String;
+
+    fn parse(input: impl BufRead) -> Self::Input {
+        input_name(input)
+    }
+
+    fn solve(name: Self::Input) -> Self::Output {
+        // Nothing to compute; the name is simply echoed back.
+        name
+    }
+
+    fn format(name: &Self::Output, mut writer: impl Write) -> io::Result<()> {
+        writeln!(writer, "Hello {name}!")
+    }
+}
+
+/// Reads a name on one line from `input` and writes `Hello <name>!` to `output`.
+///
+/// # Panics
+///
+/// Panics if `input` is empty or if any I/O error occured.
+pub fn main_impl(input: impl BufRead, output: impl Write) {
+    A1::run(input, output);
+}
+
+/// Checks that `input` follows the input format of Problem A1 exactly.
+///
+/// # Errors
+///
+/// Returns an error describing the first violation of the input format.
+pub fn validate(input: impl BufRead) -> Result<(), read_u32::ValidationError> {
+    let mut validator = read_u32::Validator::new(input);
+    let start = validator.position();
+    let name = validator.expect_line()?;
+    if name.is_empty() {
+        return Err(read_u32::ValidationError::invalid(
+            "the name must not be empty",
+            start,
+        ));
+    }
+    if let Err(error) = std::str::from_utf8(&name) {
+        return Err(read_u32::ValidationError::invalid(
+            "the name must be a UTF-8 string",
+            start.shifted(error.valid_up_to()),
+        ));
+    }
+    validator.expect_eoln()?;
+    validator.expect_eof()
 }
 
 fn input_name(input: impl BufRead) -> String {
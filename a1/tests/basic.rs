@@ -1,10 +1,6 @@
 kickstart_macros::fn_run!(a1);
 
-#[test]
-fn samples() {
-    run("SPCC", "Hello SPCC!\n");
-    run("Computer Club", "Hello Computer Club!\n");
-}
+kickstart_macros::sample_tests!(a1, "tests/samples");
 
 #[test]
 #[allow(non_snake_case)] // national security
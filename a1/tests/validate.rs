@@ -0,0 +1,16 @@
+use read_u32::Position;
+
+#[test]
+fn valid() {
+    a1::validate(&b"SPCC\n"[..]).unwrap();
+}
+
+#[test]
+fn invalid() {
+    let error = a1::validate(&b"\n"[..]).unwrap_err();
+    assert_eq!(error.position(), Position::START);
+
+    // Invalid UTF-8 is reported where it starts.
+    let error = a1::validate(&b"Ab\xc3\n"[..]).unwrap_err();
+    assert_eq!(error.position(), Position::START.shifted(2));
+}
@@ -1,35 +1,63 @@
 mod logic;
 
-use std::io::prelude::*;
-
-pub fn main_impl(input: impl BufRead, mut output: impl Write) {
-    // Note that if the input is "37.49999999..." with sufficiently many 9s,
-    // it will be parsed to 37.5 and this will return "NO".
-    // We assume this case doesn't exist, as the description
-    // "1 floating point number" implies the input should be
-    // parsable into a C/C++ `float` / `double`
-    // (corresponding to `f32` / `f64`).
-    if logic::is_normal_temperature(input_temp(input)) {
-        writeln!(output, "YES")
-    } else {
-        writeln!(output, "NO")
+use std::io::{self, prelude::*};
+
+use solution::Solution;
+use write_int::WriteInt;
+
+/// The solution to Problem A2.
+#[derive(Clone, Copy, Debug)]
+pub struct A2;
+
+impl Solution for A2 {
+    const NAME: &'static str = "A2";
+    const DESCRIPTION: &'static str = env!("CARGO_PKG_DESCRIPTION");
+
+    type Input = f64;
+    type Output = bool;
+
+    fn parse(input: impl BufRead) -> Self::Input {
+        input_temp(input)
+    }
+
+    fn solve(temperature: Self::Input) -> Self::Output {
+        logic::is_normal_temperature(temperature)
     }
-    .expect("An output error occured");
+
+    fn format(is_normal: &Self::Output, mut writer: impl Write) -> io::Result<()> {
+        writer.write_yes_no(*is_normal)?;
+        writer.write_newline()
+    }
+}
+
+/// Reads a body temperature in degrees Celsius from `input`
+/// and writes `YES` to `output` if it is normal, or `NO` otherwise.
+///
+/// # Panics
+///
+/// Panics if the temperature is not a number or if any I/O error occured.
+pub fn main_impl(input: impl BufRead, output: impl Write) {
+    A2::run(input, output);
+}
+
+/// Checks that `input` follows the input format of Problem A2 exactly.
+///
+/// # Errors
+///
+/// Returns an error describing the first violation of the input format.
+pub fn validate(input: impl BufRead) -> Result<(), read_u32::ValidationError> {
+    let mut validator = read_u32::Validator::new(input);
+    validator.expect_f64()?;
+    validator.expect_eoln()?;
+    validator.expect_eof()
 }
 
 // Input routine.
 fn input_temp(input: impl BufRead) -> f64 {
-    input
-        // Read a line of input.
-        .lines()
-        .next()
-        .expect("No input was provided")
-        .expect("An input error occured")
-        // Convert the input string to a floating point number.
-        //
-        // While one might want to test digit by digit (first digit should be 3, second should be 6 or 7, ...),
-        // there are some weird cases like `3.65e0001`, which is a valid floating point literal
-        // and the `parse` function can handle that.
-        .parse()
-        .expect("Input was not a floating-point literal")
+    // Read a line of input and convert it to a floating point number.
+    //
+    // While one might want to test digit by digit (first digit should be 3, second should be 6 or 7, ...),
+    // there are some weird cases like `3.65e0001`, which is a valid floating point literal
+    // and the reader can handle that.
+    read_u32::U32Reader::with_mode(input, read_u32::Mode::from_env()).read_until_newline()
 }
@@ -1,14 +1,10 @@
 kickstart_macros::fn_run!(a2);
 
+kickstart_macros::sample_tests!(a2, "tests/samples");
+
 const YES: &str = "YES\n";
 const NO: &str = "NO\n";
 
-#[test]
-fn samples() {
-    run("36.5", YES);
-    run("37.6", NO);
-}
-
 #[test]
 fn edge_cases() {
     // lower bound: 36.0 <= x
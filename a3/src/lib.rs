@@ -1,11 +1,71 @@
 mod logic;
 
-use std::io::prelude::*;
+use std::io::{self, prelude::*};
 
-pub fn main_impl(input: impl BufRead, mut output: impl Write) {
-    let count = logic::count_vowels(&input_words(input));
+use solution::Solution;
 
-    writeln!(output, "{count}").expect("An output error occured");
+/// The solution to Problem A3.
+#[derive(Clone, Copy, Debug)]
+pub struct A3;
+
+impl Solution for A3 {
+    const NAME: &'static str = "A3";
+    const DESCRIPTION: &'static str = env!("CARGO_PKG_DESCRIPTION");
+
+    type Input = String;
+    type Output = usize;
+
+    fn parse(input: impl BufRead) -> Self::Input {
+        input_words(input)
+    }
+
+    fn solve(words: Self::Input) -> Self::Output {
+        logic::count_vowels(&words)
+    }
+
+    fn format(count: &Self::Output, mut writer: impl Write) -> io::Result<()> {
+        writeln!(writer, "{count}")
+    }
+}
+
+/// Reads a line of text from `input` and writes the number of vowels in it to `output`.
+///
+/// # Panics
+///
+/// Panics if `input` is empty or if any I/O error occured.
+pub fn main_impl(input: impl BufRead, output: impl Write) {
+    A3::run(input, output);
+}
+
+/// Checks that `input` follows the input format of Problem A3 exactly.
+///
+/// # Errors
+///
+/// Returns an error describing the first violation of the input format.
+pub fn validate(input: impl BufRead) -> Result<(), read_u32::ValidationError> {
+    let mut validator = read_u32::Validator::new(input);
+    let start = validator.position();
+    let words = validator.expect_line()?;
+    let invalid = |offset| {
+        Err(read_u32::ValidationError::invalid(
+            "the line must contain 1 to 100 UTF-8 characters",
+            start.shifted(offset),
+        ))
+    };
+    let words = match std::str::from_utf8(&words) {
+        Ok(words) => words,
+        Err(error) => return invalid(error.valid_up_to()),
+    };
+    if words.is_empty() {
+        return invalid(0);
+    }
+    // The length is counted in characters, like `len(s)` in Python,
+    // so a line that is too long is reported at its 101st character.
+    if let Some((offset, _)) = words.char_indices().nth(100) {
+        return invalid(offset);
+    }
+    validator.expect_eoln()?;
+    validator.expect_eof()
 }
 
 // Input routine.
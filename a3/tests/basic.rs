@@ -5,11 +5,7 @@
 
 kickstart_macros::fn_run!(a3);
 
-#[test]
-fn samples() {
-    run("SPCC", "0\n");
-    run("Computer Club", "4\n");
-}
+kickstart_macros::sample_tests!(a3, "tests/samples");
 
 #[test]
 fn vowels_and_consonants() {
@@ -0,0 +1,22 @@
+use read_u32::Position;
+
+#[test]
+fn valid() {
+    a3::validate(&b"Hello, world!\n"[..]).unwrap();
+    a3::validate(format!("{}\n", "é".repeat(100)).as_bytes()).unwrap();
+}
+
+#[test]
+fn invalid() {
+    // Empty lines are reported at their start.
+    let error = a3::validate(&b"\n"[..]).unwrap_err();
+    assert_eq!(error.position(), Position::START);
+
+    // Too long lines are reported at the 101st character, which starts at byte 200 here.
+    let error = a3::validate(format!("{}\n", "é".repeat(101)).as_bytes()).unwrap_err();
+    assert_eq!(error.position(), Position::START.shifted(200));
+
+    // Invalid UTF-8 is reported where it starts.
+    let error = a3::validate(&b"ab\xffc\n"[..]).unwrap_err();
+    assert_eq!(error.position(), Position::START.shifted(2));
+}
@@ -0,0 +1,15 @@
+/// Returns the minimum number of steps to reach the lab,
+/// by finding the fewest steps to every stair in turn.
+#[must_use]
+pub fn min_steps(staircase_size: u32) -> u32 {
+    // `steps[n]` is the fewest steps to reach stair `n`.
+    let mut steps = vec![0; staircase_size as usize + 1];
+    for stair in 1..steps.len() {
+        // The last step climbs 1, 2 or 3 stairs.
+        steps[stair] = steps[stair.saturating_sub(3)..stair]
+            .iter()
+            .min()
+            .map_or(0, |fewest| fewest + 1);
+    }
+    steps[staircase_size as usize]
+}
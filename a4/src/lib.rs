@@ -1,25 +1,64 @@
+pub mod brute;
 mod logic;
 
-use std::io::prelude::*;
+use std::io::{self, prelude::*};
 
-pub fn main_impl(input: impl BufRead, mut output: impl Write) {
-    writeln!(
-        output,
-        "{steps}",
-        steps = logic::min_steps(input_stairs(input))
-    )
-    .expect("An output error occured");
+use solution::{BruteForce, Solution};
+
+/// The solution to Problem A4.
+#[derive(Clone, Copy, Debug)]
+pub struct A4;
+
+impl Solution for A4 {
+    const NAME: &'static str = "A4";
+    const DESCRIPTION: &'static str = env!("CARGO_PKG_DESCRIPTION");
+
+    type Input = u32;
+    type Output = u32;
+
+    fn parse(input: impl BufRead) -> Self::Input {
+        input_stairs(input)
+    }
+
+    fn solve(staircase_size: Self::Input) -> Self::Output {
+        logic::min_steps(staircase_size)
+    }
+
+    fn format(steps: &Self::Output, mut writer: impl Write) -> io::Result<()> {
+        writeln!(writer, "{steps}")
+    }
+}
+
+impl BruteForce for A4 {
+    fn brute_force(staircase_size: Self::Input) -> Self::Output {
+        brute::min_steps(staircase_size)
+    }
+}
+
+/// Reads the number of stairs from `input`
+/// and writes the fewest steps to climb them to `output`.
+///
+/// # Panics
+///
+/// Panics if the number of stairs is not a `u32` or if any I/O error occured.
+pub fn main_impl(input: impl BufRead, output: impl Write) {
+    A4::run(input, output);
+}
+
+/// Checks that `input` follows the input format of Problem A4 exactly.
+///
+/// # Errors
+///
+/// Returns an error describing the first violation of the input format.
+pub fn validate(input: impl BufRead) -> Result<(), read_u32::ValidationError> {
+    let mut validator = read_u32::Validator::new(input);
+    validator.expect_u32_in(1..=1_000_000)?;
+    validator.expect_eoln()?;
+    validator.expect_eof()
 }
 
 // Input routine.
 fn input_stairs(input: impl BufRead) -> u32 {
-    input
-        // Read a line of input.
-        .lines()
-        .next()
-        .expect("No input was provided")
-        .expect("An input error occured")
-        // Convert the input into an integer, panicking on failure.
-        .parse()
-        .expect("Input was not a an integer")
+    // Read a line of input and convert it into an integer, panicking on failure.
+    read_u32::U32Reader::with_mode(input, read_u32::Mode::from_env()).read_until_newline()
 }
@@ -1,10 +1,6 @@
 kickstart_macros::fn_run!(a4);
 
-#[test]
-fn samples() {
-    run("15", "5\n");
-    run("100", "34\n");
-}
+kickstart_macros::sample_tests!(a4, "tests/samples");
 
 // Unfortunately, our tests have to assume that our way of
 // climbing stairs is correct.
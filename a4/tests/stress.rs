@@ -0,0 +1,22 @@
+use quickcheck::{Arbitrary, Gen};
+use solution::Inputs;
+
+/// A small staircase.
+struct Staircase;
+
+impl Inputs for Staircase {
+    type Value = u32;
+
+    fn generate(g: &mut Gen) -> u32 {
+        u32::arbitrary(g) % 100 + 1
+    }
+
+    fn is_valid(&size: &u32) -> bool {
+        size >= 1
+    }
+}
+
+#[test]
+fn stress() {
+    solution::stress::<a4::A4, Staircase>(1000);
+}
@@ -0,0 +1,12 @@
+/// Returns the number of packs of potato chips one can buy,
+/// by buying them one by one until the money runs out.
+#[must_use]
+pub const fn max_packs(price: u32, mut wallet: u32) -> u32 {
+    let mut packs = 0;
+    // The next pack costs one more `price` than the last.
+    while let Some(left) = wallet.checked_sub((packs + 1) * price) {
+        wallet = left;
+        packs += 1;
+    }
+    packs
+}
@@ -1,28 +1,119 @@
-use std::io::prelude::*;
+use std::io::{self, prelude::*};
 
+use solution::{BruteForce, Solution};
+use write_int::WriteInt;
+
+pub mod brute;
 mod logic;
 
 // public for direct testing
 pub use logic::max_packs;
 
-pub fn main_impl(input: impl BufRead, mut output: impl Write) {
-    // Repeat the computation and printing for each pair of input.
-    for (price, wallet) in input_price_wallet(input) {
-        writeln!(output, "{packs}", packs = max_packs(price, wallet))
-            .expect("An output error occured");
+/// The solution to Problem A5.
+#[derive(Clone, Copy, Debug)]
+pub struct A5;
+
+/// A test case of Problem A5.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, read_u32::KickstartInput)]
+pub struct TestCase {
+    /// The price of the first pack, which each next pack costs one more of.
+    #[sep = "space"]
+    pub price: u32,
+    /// The money in the wallet.
+    #[line]
+    pub wallet: u32,
+}
+
+impl From<(u32, u32)> for TestCase {
+    fn from((price, wallet): (u32, u32)) -> Self {
+        Self { price, wallet }
     }
 }
 
+impl Solution for A5 {
+    const NAME: &'static str = "A5";
+    const DESCRIPTION: &'static str = env!("CARGO_PKG_DESCRIPTION");
+
+    type Input = Vec<TestCase>;
+    type Output = Vec<u32>;
+
+    fn parse(input: impl BufRead) -> Self::Input {
+        input_price_wallet(input)
+    }
+
+    fn solve(test_cases: Self::Input) -> Self::Output {
+        // Repeat the computation for each pair of input.
+        test_cases
+            .into_iter()
+            .map(|TestCase { price, wallet }| max_packs(price, wallet))
+            .collect()
+    }
+
+    fn format(packs: &Self::Output, mut writer: impl Write) -> io::Result<()> {
+        for &packs in packs {
+            writer.write_u32(packs)?;
+            writer.write_newline()?;
+        }
+        Ok(())
+    }
+}
+
+impl BruteForce for A5 {
+    fn brute_force(test_cases: Self::Input) -> Self::Output {
+        test_cases
+            .into_iter()
+            .map(|TestCase { price, wallet }| brute::max_packs(price, wallet))
+            .collect()
+    }
+}
+
+/// Reads the number of test cases from `input`, then a price and a wallet on each line,
+/// and writes the most packs of chips that each wallet can buy to `output`, one per line.
+///
+/// # Panics
+///
+/// Panics if the input is malformed, a price is 0, a wallet has more than $1000000,
+/// or if any I/O error occured.
+pub fn main_impl(input: impl BufRead, output: impl Write) {
+    A5::run(input, output);
+}
+
+/// Checks that `input` follows the input format of Problem A5 exactly.
+///
+/// # Errors
+///
+/// Returns an error describing the first violation of the input format.
+pub fn validate(input: impl BufRead) -> Result<(), read_u32::ValidationError> {
+    let mut validator = read_u32::Validator::new(input);
+    let test_case_count = validator.expect_u32_in(1..=solution::MAX_TEST_CASES)?;
+    validator.expect_eoln()?;
+    for _ in 0..test_case_count {
+        validator.expect_u32_in(1..=1000)?; // price
+        validator.expect_space()?;
+        validator.expect_u32_in(1..=1_000_000)?; // wallet
+        validator.expect_eoln()?;
+    }
+    validator.expect_eof()
+}
+
 // Input routine.
-fn input_price_wallet(input: impl BufRead) -> impl Iterator<Item = (u32, u32)> {
-    let mut reader = read_u32::U32Reader::new(input);
+#[derive(read_u32::KickstartInput)]
+struct Input {
+    #[count_prefixed]
+    test_cases: Vec<TestCase>,
+}
 
-    let test_case_count = reader.read_until_newline();
+fn input_price_wallet(input: impl BufRead) -> Vec<TestCase> {
+    let mut reader = read_u32::U32Reader::with_mode(input, read_u32::Mode::from_env());
+    let test_cases = <Input as read_u32::KickstartInput>::read_from(&mut reader).test_cases;
 
-    std::iter::repeat_with(move || {
-        let price = reader.read_until_space();
-        let wallet = reader.read_until_newline();
-        (price, wallet)
-    })
-    .take(test_case_count as usize)
+    // `max_packs` divides by the price, and relies on the wallet being small to avoid overflow.
+    for &TestCase { price, wallet } in &test_cases {
+        assert!(price != 0, "The price must be positive");
+        assert!(
+            wallet <= 1_000_000,
+            "The wallet must have at most $1000000, but it has ${wallet}"
+        );
+    }
+    test_cases
 }
@@ -1 +1 @@
-kickstart_macros::fn_main!(a5);
+kickstart_macros::fn_main!(a5, slurp);
@@ -1,9 +1,6 @@
 kickstart_macros::fn_run!(a5);
 
-#[test]
-fn sample() {
-    run("2\n1 3\n1 11", "2\n4\n");
-}
+kickstart_macros::sample_tests!(a5, "tests/samples");
 
 #[test]
 fn long() {
@@ -39,3 +36,30 @@ fn long() {
     );
     // This is rather tedious, so we delegate further testing of `max_packs` to `max_packs.rs`.
 }
+
+// Found by fuzzing, as a division by zero.
+#[test]
+#[should_panic(expected = "The price must be positive")]
+fn zero_price() {
+    run("1\n0 1\n", "");
+}
+
+/// A writer that fails like `/dev/full`.
+struct Full;
+
+impl std::io::Write for Full {
+    fn write(&mut self, _: &[u8]) -> std::io::Result<usize> {
+        Err(std::io::Error::other("No space left on device"))
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+// The output fits in the buffer, so the error only shows up when the buffer is flushed.
+#[test]
+#[should_panic(expected = "An output error occured")]
+fn output_error() {
+    a5::main_impl(&b"1\n3 10\n"[..], write_int::IntWriter::new(Full));
+}
@@ -0,0 +1,26 @@
+#[test]
+fn valid() {
+    a5::validate(&b"2\n1 3\n1000 1000000\n"[..]).unwrap();
+}
+
+#[test]
+fn out_of_bounds() {
+    // price > 1000
+    assert!(a5::validate(&b"1\n1001 3\n"[..]).is_err());
+    // wallet > 10^6
+    assert!(a5::validate(&b"1\n1 1000001\n"[..]).is_err());
+    // price = 0
+    assert!(a5::validate(&b"1\n0 3\n"[..]).is_err());
+}
+
+#[test]
+fn malformed() {
+    // missing final newline
+    assert!(a5::validate(&b"1\n1 3"[..]).is_err());
+    // fewer test cases than declared
+    assert!(a5::validate(&b"2\n1 3\n"[..]).is_err());
+    // more test cases than declared
+    assert!(a5::validate(&b"1\n1 3\n1 3\n"[..]).is_err());
+    // a tab instead of a space
+    assert!(a5::validate(&b"1\n1\t3\n"[..]).is_err());
+}
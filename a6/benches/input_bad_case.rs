@@ -0,0 +1,81 @@
+use std::fmt::Write;
+
+use a6_benchgen::bad_case;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use rand::{seq::SliceRandom, SeedableRng};
+use read_u32::SliceReader;
+
+/// Builds an input file with exactly 10^6 numbers,
+/// like `a6_benchgen --fill --deterministic` does.
+fn input_bad_case() -> String {
+    let mut numbers: Vec<u32> = bad_case().collect();
+
+    let mut randomizer = rand::rngs::StdRng::seed_from_u64(0);
+    numbers.shuffle(&mut randomizer);
+    numbers.extend_from_within(..1_000_000 - numbers.len());
+    numbers.shuffle(&mut randomizer);
+
+    let mut input = format!("{len}\n", len = numbers.len());
+    for number in &numbers {
+        write!(input, "{number} ").unwrap();
+    }
+    // Replace the trailing space with a newline.
+    input.pop();
+    input.push('\n');
+    input
+}
+
+fn input(c: &mut Criterion) {
+    let input = input_bad_case();
+
+    let mut group = c.benchmark_group("input");
+
+    group.bench_function("read_row", |b| {
+        b.iter(|| {
+            let mut reader = SliceReader::new(black_box(input.as_bytes()));
+            let count = reader.read_until_newline();
+            black_box(reader.read_row::<u32>(count))
+        });
+    });
+
+    group.bench_function("read_one_by_one", |b| {
+        b.iter(|| {
+            let mut reader = SliceReader::new(black_box(input.as_bytes()));
+            let count: usize = reader.read_until_newline();
+            let mut numbers: Vec<u32> = Vec::with_capacity(count);
+            for _ in 0..count - 1 {
+                numbers.push(reader.read_until_space());
+            }
+            numbers.push(reader.read_until_newline());
+            black_box(numbers)
+        });
+    });
+
+    group.finish();
+}
+
+fn main_impl(c: &mut Criterion) {
+    let input = input_bad_case();
+
+    let mut group = c.benchmark_group("main_impl");
+
+    // The whole input is in memory, like with `fn_main!(a6, slurp)`.
+    group.bench_function("slice", |b| {
+        b.iter(|| a6::main_impl(black_box(input.as_bytes()), std::io::sink()));
+    });
+
+    // The input comes in small chunks, like standard input without `slurp`.
+    group.bench_function("buf_reader", |b| {
+        b.iter(|| {
+            a6::main_impl(
+                std::io::BufReader::new(black_box(input.as_bytes())),
+                std::io::sink(),
+            );
+        });
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, input, main_impl);
+criterion_main!(benches);
@@ -1,10 +1,25 @@
 use a6_benchgen::bad_case;
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
 
+/// Benchmarks `solve` on `numbers` as `name/sequential`,
+/// and `solve_parallel` as `name/parallel` if the `parallel` feature is enabled:
+/// ```text
+/// cargo bench --bench solve_bad_case --features parallel
+/// ```
+fn bench_solvers(c: &mut Criterion, name: &str, numbers: &[u32]) {
+    let mut group = c.benchmark_group(name);
+    group.bench_function("sequential", |b| b.iter(|| black_box(a6::solve(numbers))));
+    #[cfg(feature = "parallel")]
+    group.bench_function("parallel", |b| {
+        b.iter(|| black_box(a6::solve_parallel(numbers)));
+    });
+    group.finish();
+}
+
 fn no_solution(c: &mut Criterion) {
     let bad: Vec<u32> = bad_case().collect();
 
-    c.bench_function("no_solution", |b| b.iter(|| black_box(a6::solve(&bad))));
+    bench_solvers(c, "no_solution", &bad);
 }
 
 fn with_solution(c: &mut Criterion) {
@@ -21,9 +36,7 @@ fn with_solution(c: &mut Criterion) {
     let insert_magic_point = bad.binary_search(&MAGIC_NUMBER).unwrap_err();
     bad.insert(insert_magic_point, MAGIC_NUMBER);
 
-    c.bench_function("with_solution", |b| {
-        b.iter(|| black_box(a6::solve(&bad)));
-    });
+    bench_solvers(c, "with_solution", &bad);
 }
 
 fn with_repeat(c: &mut Criterion) {
@@ -33,26 +46,33 @@ fn with_repeat(c: &mut Criterion) {
     let mut rng = rand::thread_rng();
 
     let bad: Vec<u32> = bad_case()
-        .into_iter()
         // Repetition probabilities: x1: 40%, x2: 30%, x3: 20%, x4: 10%.
         // These probabilities are defined above with WeightedIndex.
-        .flat_map(|num| std::iter::repeat(num).take(random_repeat.sample(&mut rng) + 1))
+        .flat_map(|num| std::iter::repeat_n(num, random_repeat.sample(&mut rng) + 1))
         .take(1_000_000)
         .collect();
 
-    c.bench_function("with_repeat", |b| {
-        b.iter(|| black_box(a6::solve(&bad)));
-    });
+    bench_solvers(c, "with_repeat", &bad);
 }
 
 fn extreme_repetition(c: &mut Criterion) {
     let degenerate: Vec<u32> = (2..=999)
-        .chain(std::iter::repeat(1009).take(1_000_000 - 998 - 1))
+        .chain(std::iter::repeat_n(1009, 1_000_000 - 998 - 1))
         .chain(std::iter::once(1_000_000))
         .collect();
 
-    c.bench_function("extreme_repetition", |b| {
-        b.iter(|| black_box(a6::solve(&degenerate)));
+    bench_solvers(c, "extreme_repetition", &degenerate);
+}
+
+fn all_triples(c: &mut Criterion) {
+    // Every number is here, so this has the most triples of any input: almost 6 million.
+    let all: Vec<u32> = (2..=1_000_000).collect();
+
+    c.bench_function("count_all_triples", |b| {
+        b.iter(|| black_box(a6::count_triples(&all)));
+    });
+    c.bench_function("enumerate_all_triples", |b| {
+        b.iter(|| black_box(a6::triples(&all)));
     });
 }
 
@@ -62,5 +82,6 @@ criterion_group!(
     with_solution,
     with_repeat,
     extreme_repetition,
+    all_triples,
 );
 criterion_main!(benches);
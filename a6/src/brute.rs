@@ -0,0 +1,32 @@
+use std::collections::BTreeSet;
+
+/// Solves Problem A6 by trying every pair of numbers as `a` and `b`.
+/// The numbers need not be sorted.
+#[must_use]
+pub fn solve(numbers: &[u32]) -> Option<u32> {
+    let mut best_c = None;
+    for (i, &a) in numbers.iter().enumerate() {
+        for &b in &numbers[i + 1..] {
+            let product = u64::from(a) * u64::from(b);
+            let c = numbers.iter().find(|&&c| u64::from(c) == product).copied();
+            best_c = best_c.max(c);
+        }
+    }
+    best_c
+}
+
+/// Finds every triple like [`crate::triples`] by trying every pair of numbers as `a` and `b`.
+/// The numbers need not be sorted, and the triples are returned in increasing order.
+#[must_use]
+pub fn triples(numbers: &[u32]) -> Vec<(u32, u32, u32)> {
+    let mut triples = BTreeSet::new();
+    for (i, &a) in numbers.iter().enumerate() {
+        for &b in &numbers[i + 1..] {
+            let product = u64::from(a) * u64::from(b);
+            if let Some(&c) = numbers.iter().find(|&&c| u64::from(c) == product) {
+                triples.insert((a.min(b), a.max(b), c));
+            }
+        }
+    }
+    triples.into_iter().collect()
+}
@@ -1,35 +1,155 @@
-use std::io::prelude::*;
+use std::io::{self, prelude::*};
 
+use solution::{BruteForce, Solution};
+use write_int::WriteInt;
+
+pub mod brute;
 mod logic;
+pub mod table;
 
 // public for direct testing
-pub use logic::solve;
+#[cfg(feature = "parallel")]
+pub use logic::solve_parallel;
+pub use logic::{count_triples, solve, solve_with_witness, triples};
+
+/// The solution to Problem A6.
+#[derive(Clone, Copy, Debug)]
+pub struct A6;
+
+impl Solution for A6 {
+    const NAME: &'static str = "A6";
+    const DESCRIPTION: &'static str = env!("CARGO_PKG_DESCRIPTION");
+
+    type Input = Vec<u32>;
+    type Output = Option<u32>;
+
+    fn parse(input: impl BufRead) -> Self::Input {
+        input_numbers(input)
+    }
+
+    fn solve(mut numbers: Self::Input) -> Self::Output {
+        // Sort the input numbers for `solve`.
+        numbers.sort_unstable();
+        search(&numbers)
+    }
+
+    fn format(answer: &Self::Output, mut writer: impl Write) -> io::Result<()> {
+        // Print -1 if there is no answer.
+        writer.write_or_minus_one(*answer)?;
+        writer.write_newline()
+    }
+}
+
+/// Searches on every thread, since the `parallel` feature is enabled.
+#[cfg(feature = "parallel")]
+fn search(numbers: &[u32]) -> Option<u32> {
+    solve_parallel(numbers)
+}
+
+/// Searches on the current thread, since the `parallel` feature is disabled.
+#[cfg(not(feature = "parallel"))]
+fn search(numbers: &[u32]) -> Option<u32> {
+    solve(numbers)
+}
+
+impl BruteForce for A6 {
+    fn brute_force(numbers: Self::Input) -> Self::Output {
+        brute::solve(&numbers)
+    }
+}
+
+/// The solution to Problem A6, which prints the product triple `a b c` rather than only `c`.
+///
+/// This is not what the statement asks for, but it shows which pair gives the answer,
+/// and the A6 checker of the `kickstart` judge accepts it.
+#[derive(Clone, Copy, Debug)]
+pub struct A6Witness;
+
+impl Solution for A6Witness {
+    const NAME: &'static str = A6::NAME;
+    const DESCRIPTION: &'static str = A6::DESCRIPTION;
+
+    type Input = Vec<u32>;
+    type Output = Option<(u32, u32, u32)>;
+
+    fn parse(input: impl BufRead) -> Self::Input {
+        A6::parse(input)
+    }
+
+    fn solve(mut numbers: Self::Input) -> Self::Output {
+        numbers.sort_unstable();
+        solve_with_witness(&numbers)
+    }
+
+    fn format(answer: &Self::Output, mut writer: impl Write) -> io::Result<()> {
+        match *answer {
+            Some((a, b, c)) => writer.write_row(<[u32; 3]>::from((a, b, c)))?,
+            // Print -1 if there is no answer, like `A6`.
+            None => writer.write_or_minus_one(None::<u32>)?,
+        }
+        writer.write_newline()
+    }
+}
 
-pub fn main_impl(input: impl BufRead, mut output: impl Write) {
-    let mut numbers = input_numbers(input);
+/// Reads the count of numbers from `input` and then the numbers on one line,
+/// and writes a number that is the product of two others to `output`, or `-1` if there is none.
+///
+/// # Panics
+///
+/// Panics if the input is malformed, there are more than 1000000 numbers, a number is below 2,
+/// or if any I/O error occured.
+pub fn main_impl(input: impl BufRead, output: impl Write) {
+    A6::run(input, output);
+}
 
-    // Sort the input numbers for `solve`.
-    numbers.sort_unstable();
+/// Reads the input of Problem A6 like [`main_impl`],
+/// but writes `a b c` with `a * b = c` instead of only `c`, as [`A6Witness`] does.
+///
+/// # Panics
+///
+/// Panics if the input is malformed, there are more than 1000000 numbers, a number is below 2,
+/// or if any I/O error occured.
+pub fn main_impl_witness(input: impl BufRead, output: impl Write) {
+    A6Witness::run(input, output);
+}
 
-    match solve(&numbers) {
-        Some(num) => writeln!(output, "{num}"),
-        None => writeln!(output, "-1"),
+/// Checks that `input` follows the input format of Problem A6 exactly.
+///
+/// # Errors
+///
+/// Returns an error describing the first violation of the input format.
+pub fn validate(input: impl BufRead) -> Result<(), read_u32::ValidationError> {
+    let mut validator = read_u32::Validator::new(input);
+    let count = validator.expect_u32_in(1..=1_000_000)?;
+    validator.expect_eoln()?;
+    for i in 0..count {
+        if i != 0 {
+            validator.expect_space()?;
+        }
+        validator.expect_u32_in(2..=1_000_000)?;
     }
-    .expect("An output error occured");
+    validator.expect_eoln()?;
+    validator.expect_eof()
 }
 
 // Input routine.
 fn input_numbers(input: impl BufRead) -> Vec<u32> {
-    let mut reader = read_u32::U32Reader::new(input);
+    let mut reader = read_u32::U32Reader::with_mode(input, read_u32::Mode::from_env());
 
     // Read the number of test cases.
-    let test_case_count = reader.read_until_newline() as usize;
+    let test_case_count = reader.read_until_newline();
+    // Don't allocate space for more numbers than there can be.
+    assert!(
+        test_case_count <= 1_000_000,
+        "Too many numbers: {test_case_count}"
+    );
 
-    let mut numbers = Vec::with_capacity(test_case_count);
-    for _ in 0..test_case_count - 1 {
-        numbers.push(reader.read_until_space());
-    }
-    numbers.push(reader.read_until_newline());
+    // Read all the numbers in the next row at once.
+    let numbers = reader.read_row(test_case_count);
 
+    // `solve` handles any number up to `u32::MAX`, but not 0 or 1.
+    if let Some(number) = numbers.iter().find(|&&n| n < 2) {
+        panic!("The numbers must be at least 2, but {number} was found");
+    }
     numbers
 }
@@ -1,3 +1,7 @@
+use std::ops::ControlFlow;
+
+use crate::table::{BitSet, PresenceTable, Sorted};
+
 // We are looking for some numbers `(a, b, c)` among the inputs such that `a * b = c`.
 // Without loss of generality, assume a <= b (< c).
 // Because a * a <= a * b = c <= max, the range of `a` is pretty small.
@@ -101,49 +105,197 @@
 //   [1 + 1/2 + 1/3 + ... + 1/x is approximately ln(x) or "the logarithm of `x` base e",
 //   source: <https://en.wikipedia.org/wiki/Harmonic_number>]
 // = O(max * log(max)) [log properties].
+//
+// That is with a `BitSet` as the search table (see `table.rs`).
+// When the numbers are few but huge, such as [2, 3, 4294967295], a bitset would be too large,
+// so a `Sorted` table is used instead. Each lookup then takes O(log(n)) time,
+// and Step 3 takes O(min(n, max / a) * log(n)) time.
 
-/// Solves Problem A6. Requires inputs to be sorted and between 2 and 10^6.
+/// Solves Problem A6. Requires inputs to be sorted and at least 2.
 #[must_use]
-pub fn solve(mut numbers: &[u32]) -> Option<u32> {
+pub fn solve(numbers: &[u32]) -> Option<u32> {
+    solve_with_witness(numbers).map(|(_, _, c)| c)
+}
+
+/// Solves Problem A6 like [`solve`], but also returns the `a` and `b` whose product is `c`,
+/// as `(a, b, c)` with `a <= b`.
+///
+/// If more than one pair gives the largest `c`, the one with the smallest `a` is returned.
+#[must_use]
+pub fn solve_with_witness(numbers: &[u32]) -> Option<(u32, u32, u32)> {
     // The maximum value among all numbers.
     // Return if there are no input numbers.
     let &max = numbers.last()?;
 
-    let mut current_best_c: Option<u32> = None;
-
     // Use a search table to improve search performance.
-    let numbers_table = numbers
-        .iter()
-        // For `numbers_table[max]` to be valid, create a table with `max` + 1 entries.
-        // This uses not O(n) but O(max) memory, so if the input is [2, 3, 1_000_000],
-        // we will still allocate 1 MB of memory.
-        // But we don't have a memory limit and 1 MB is negligible compared to firing up Python or Scratch,
-        // so it doesn't matter.
-        //
-        // Theoretically we can use HashSet (O(n) memory) or BitVec (1/8 of current allocation),
-        // but searching in them involves a rather significant constant time factor.
-        .fold(vec![false; (max + 1) as usize], |mut table, &num| {
-            table[num as usize] = true;
-            table
+    if BitSet::is_better(numbers.len(), max) {
+        search_best(numbers, max, &BitSet::new(numbers, max))
+    } else {
+        search_best(numbers, max, &Sorted::new(numbers))
+    }
+}
+
+/// Does the work of [`solve_with_witness`] with `numbers_table` as the search table.
+fn search_best(
+    numbers: &[u32],
+    max: u32,
+    numbers_table: &impl PresenceTable,
+) -> Option<(u32, u32, u32)> {
+    let mut current_best: Option<(u32, u32, u32)> = None;
+
+    // Step 1.
+    for (a, numbers) in distinct_as(numbers, max) {
+        // Step 2.
+
+        // Set the search range for `b`.
+        let bs = {
+            let bs = bs(a, numbers, max);
+
+            // If we have a current best `c`, find the lower bound for `b`.
+            // Extract the part of `bs` whose values are strictly larger than the lower bound.
+            // (For `b`s equal to the lower bound, `a` * `b` will not be larger than the current best `c`.)
+            let bs_begin = current_best.map_or(0, |(_, _, best_c)| {
+                let b_min = best_c / a;
+                bs.partition_point(|&b| b <= b_min)
+            });
+
+            &bs[bs_begin..]
+        };
+
+        // Step 3.
+
+        // Find the largest `b` whose `c` is within the set of numbers.
+        // In Step 2 we have already ensured that if any new `c` is found,
+        // it must be better than the previous one(s).
+        if let ControlFlow::Break(b) = try_products(a, bs, numbers_table, ControlFlow::Break) {
+            // Update the records.
+            current_best = Some((a, b, a * b));
+        }
+    }
+
+    current_best
+}
+
+/// Solves Problem A6 like [`solve`], but tries several `a`s at once on the threads of `rayon`.
+/// Requires inputs to be sorted and at least 2.
+#[cfg(feature = "parallel")]
+#[must_use]
+pub fn solve_parallel(numbers: &[u32]) -> Option<u32> {
+    let &max = numbers.last()?;
+
+    if BitSet::is_better(numbers.len(), max) {
+        search_best_parallel(numbers, max, &BitSet::new(numbers, max))
+    } else {
+        search_best_parallel(numbers, max, &Sorted::new(numbers))
+    }
+}
+
+/// Does the work of [`solve_parallel`] with `numbers_table` as the search table.
+#[cfg(feature = "parallel")]
+fn search_best_parallel(
+    numbers: &[u32],
+    max: u32,
+    numbers_table: &(impl PresenceTable + Sync),
+) -> Option<u32> {
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    use rayon::prelude::*;
+
+    // The best `c` that any thread has found so far, shared so that every thread can prune with it.
+    // `c` cannot be 0, so 0 means that none has been found, like `previous_a` in `distinct_as`.
+    let current_best_c = AtomicU32::new(0);
+
+    // Step 1, collected so that rayon can split the `a`s across threads.
+    // The smaller an `a`, the more `b`s it has, so the work is uneven;
+    // rayon's work stealing keeps the threads busy anyway.
+    let a_s: Vec<_> = distinct_as(numbers, max).collect();
+
+    a_s.into_par_iter().for_each(|(a, numbers)| {
+        // Step 2, with the lower bound for `b` from the best `c` by now,
+        // which may have been found by another thread with a larger `a`.
+        let bs = bs(a, numbers, max);
+        let b_min = current_best_c.load(Ordering::Relaxed) / a;
+        let bs = &bs[bs.partition_point(|&b| b <= b_min)..];
+
+        // Step 3.
+        if let ControlFlow::Break(b) = try_products(a, bs, numbers_table, ControlFlow::Break) {
+            // Another thread may have found an even better `c` since we loaded it.
+            current_best_c.fetch_max(a * b, Ordering::Relaxed);
+        }
+    });
+
+    // Every thread has been joined, so every update is visible, whatever the ordering above.
+    match current_best_c.into_inner() {
+        0 => None,
+        c => Some(c),
+    }
+}
+
+/// Returns every distinct triple `(a, b, c)` of numbers among the inputs such that `a * b = c`
+/// and `a <= b`. Requires inputs to be sorted and at least 2.
+///
+/// `a` and `b` may only be the same number if it appears at least twice.
+/// The triples are ordered by `a`, then from the largest `b` to the smallest.
+#[must_use]
+pub fn triples(numbers: &[u32]) -> Vec<(u32, u32, u32)> {
+    let mut triples = Vec::new();
+    for_each_triple(numbers, |a, b| triples.push((a, b, a * b)));
+    triples
+}
+
+/// Returns the number of triples that [`triples`] would return, without collecting them.
+/// Requires inputs to be sorted and at least 2.
+#[must_use]
+pub fn count_triples(numbers: &[u32]) -> u64 {
+    let mut count = 0;
+    for_each_triple(numbers, |_, _| count += 1);
+    count
+}
+
+/// Calls `f` with the `a` and `b` of every triple that [`triples`] returns, in the same order.
+fn for_each_triple(numbers: &[u32], f: impl FnMut(u32, u32)) {
+    let Some(&max) = numbers.last() else {
+        return;
+    };
+    if BitSet::is_better(numbers.len(), max) {
+        search_all(numbers, max, &BitSet::new(numbers, max), f);
+    } else {
+        search_all(numbers, max, &Sorted::new(numbers), f);
+    }
+}
+
+/// Does the work of [`for_each_triple`] with `numbers_table` as the search table.
+fn search_all(
+    numbers: &[u32],
+    max: u32,
+    numbers_table: &impl PresenceTable,
+    mut f: impl FnMut(u32, u32),
+) {
+    // The same steps as `solve_with_witness`, except that `bs` never shrinks,
+    // as we want every `b` rather than only those giving a larger `c`.
+    for (a, numbers) in distinct_as(numbers, max) {
+        let _: ControlFlow<()> = try_products(a, bs(a, numbers, max), numbers_table, |b| {
+            f(a, b);
+            ControlFlow::Continue(())
         });
+    }
+}
 
+/// Returns each distinct `a` among the sorted `numbers` such that `a` * `a` <= `max`,
+/// along with the numbers after it, which are where `b` can be.
+///
+/// Only the first copy of `a` is removed, so `b` can be another copy of `a`.
+fn distinct_as(mut numbers: &[u32], max: u32) -> impl Iterator<Item = (u32, &[u32])> {
     // Remember the value of `a` in the previous iteration.
     // `a` cannot be 0, but the "correct" solution `Option<NonZeroU32>` is quite clumsy,
     // so we just use 0 as a sentinel.
     let mut previous_a = 0;
 
-    loop {
-        // Step 1.
-
-        // Extract `a` from the data.
-        let (&a, tail) = numbers
-            .split_first()
-            // For the first iteration, if there are no inputs, we should have returned when getting `max`.
-            // For the other iterations, if there are no more numbers,
-            // we should have returned below, because `max` * `max` > `max`.
-            .unwrap_or_else(|| unreachable!());
-
-        // Remove `a` from `numbers`.
+    std::iter::from_fn(move || loop {
+        // Extract `a` from the data, and remove `a` from `numbers`.
+        // If there are no more numbers, we are done;
+        // this only happens for a single number, as `max` * `max` > `max`.
+        let (&a, tail) = numbers.split_first()?;
         numbers = tail;
 
         // Check that we are not repeating our work.
@@ -157,91 +309,80 @@ pub fn solve(mut numbers: &[u32]) -> Option<u32> {
         //  On the third iteration we would realize 92682u32 * 92682u32 = 18532u32 < 92684u32,
         //  so we would continue if without the guard.)
         if u64::from(a) * u64::from(a) > u64::from(max) {
-            break;
+            return None;
         }
 
-        // Step 2.
-
-        // Set the search range for `b`.
-        let bs = {
-            // Find the upper bound for `b`.
-            let b_max = max / a;
-            // Extract the part of `numbers` whose values are no more than the upper bound.
-            let bs_end = numbers.partition_point(|&b| b <= b_max);
-
-            match current_best_c {
-                Some(best_c) => {
-                    // If we have a current best `c`, find the lower bound for `b`.
-                    let b_min = best_c / a;
-                    // Extract the part of `numbers` whose values are strictly larger than the lower bound.
-                    // (For `b`s equal to the lower bound, `a` * `b` will not be larger than the current best `c`.)
-                    let bs_begin = numbers.partition_point(|&b| b <= b_min);
-                    &numbers[bs_begin..bs_end]
-                }
-                None => &numbers[..bs_end],
-            }
-        };
+        return Some((a, numbers));
+    })
+}
 
-        // Step 3.
+/// Returns the part of `numbers` that is no more than `max` / `a`,
+/// as a larger `b` would make `c` larger than every number.
+fn bs(a: u32, numbers: &[u32], max: u32) -> &[u32] {
+    // Find the upper bound for `b`.
+    let b_max = max / a;
+    // Extract the part of `numbers` whose values are no more than the upper bound.
+    &numbers[..numbers.partition_point(|&b| b <= b_max)]
+}
 
-        // There are two ways we can iterate through `bs` from largest to smallest:
-        // 1. Iterating over the `bs` slice directly.
-        // 2. Iterating over the `true` entries in `numbers_table` that correspond to `bs`.
-        //
-        // The first method requires O(`bs.len()`) time,
-        // while the second method requires O(last value in bs - first value in bs) time.
-        // In particular, the first one is at most around `n` while the second is at most around `max` / `a`.
-        //
-        // We dynamically select between these two methods to improve performance.
-        // (See the `extreme_repetition` benchmark for a case that would be
-        // extremely slow if only method 1 is used.)
-
-        let method_1 = || {
-            bs.iter()
-                // Iterate from largest to smallest.
-                .rev()
-                // Calculate `c`.
-                .map(move |&b| a * b)
-                // Look for a `c` that is within the set of numbers.
-                .find(|&c| numbers_table[c as usize])
-        };
+/// Calls `f` with every distinct `b` in `bs`, from largest to smallest,
+/// such that `c` = `a` * `b` is within the set of numbers, until `f` breaks.
+fn try_products<B>(
+    a: u32,
+    bs: &[u32],
+    numbers_table: &impl PresenceTable,
+    f: impl FnMut(u32) -> ControlFlow<B>,
+) -> ControlFlow<B> {
+    // There are two ways we can iterate through `bs` from largest to smallest:
+    // 1. Iterating over the `bs` slice directly.
+    // 2. Iterating over the values between the first and last `b` that `numbers_table` contains.
+    //
+    // The first method requires O(`bs.len()`) time,
+    // while the second method requires O(last value in bs - first value in bs) time.
+    // In particular, the first one is at most around `n` while the second is at most around `max` / `a`.
+    //
+    // We dynamically select between these two methods to improve performance.
+    // (See the `extreme_repetition` benchmark for a case that would be
+    // extremely slow if only method 1 is used.)
 
-        let method_2 = |first: u32, last: u32| {
-            (first..=last)
-                // Iterate from largest to smallest.
-                .rev()
-                // Only consider those between `first` and `last` that actually exist in `bs`.
-                .filter(|&b| numbers_table[b as usize])
-                // Calculate `c`.
-                .map(move |b| a * b)
-                // Look for a `c` that is within the set of numbers.
-                .find(|&c| numbers_table[c as usize])
-        };
+    let method_1 = |f| {
+        let mut previous_b = 0;
+        bs.iter()
+            // Iterate from largest to smallest.
+            .rev()
+            // Skip the repeated copies of a `b`, like for `a`.
+            .filter(|&&b| std::mem::replace(&mut previous_b, b) != b)
+            // Look for a `c` that is within the set of numbers.
+            .filter(|&&b| numbers_table.contains(a * b))
+            .copied()
+            .try_for_each(f)
+    };
 
-        // We know `bs.len()` is not more than 10^6, so converting into a `u32` does not truncate.
-        #[allow(clippy::cast_possible_truncation)]
-        let new_c = match bs {
-            // Heuristics for checking if Method 2 would be faster.
-            &[first, .., last]
-                if {
-                    // Make it slightly more unlikely for Method 2 to be chosen,
-                    // because it's generally slower when the data is uniformly distibuted.
-                    const METHOD_2_THRESHOLD_RATIO: u32 = 2;
-                    (last - first) * METHOD_2_THRESHOLD_RATIO < (bs.len() as u32)
-                } =>
-            {
-                method_2(first, last)
-            }
-            _ => method_1(),
-        };
+    let method_2 = |first: u32, last: u32, f| {
+        (first..=last)
+            // Iterate from largest to smallest.
+            .rev()
+            // Only consider those between `first` and `last` that actually exist in `bs`.
+            .filter(|&b| numbers_table.contains(b))
+            // Look for a `c` that is within the set of numbers.
+            .filter(|&b| numbers_table.contains(a * b))
+            .try_for_each(f)
+    };
 
-        // Update the records.
-        // In Step 2 we have already ensured that if any new `c` is found,
-        // it must be better than the previous one(s).
-        if let Some(c) = new_c {
-            current_best_c = Some(c);
+    match *bs {
+        // Heuristics for checking if Method 2 would be faster.
+        [first, .., last]
+            if {
+                // Make it slightly more unlikely for Method 2 to be chosen,
+                // because it's generally slower when the data is uniformly distibuted.
+                const METHOD_2_THRESHOLD_RATIO: u64 = 2;
+                // Numbers up to `u32::MAX` could overflow a `u32`.
+                u64::from(last - first) * METHOD_2_THRESHOLD_RATIO
+                    < u64::try_from(bs.len()).unwrap_or(u64::MAX)
+            } =>
+        {
+            method_2(first, last, f)
         }
+        _ => method_1(f),
     }
-
-    current_best_c
 }
@@ -1 +1 @@
-kickstart_macros::fn_main!(a6);
+kickstart_macros::fn_main!(a6, slurp);
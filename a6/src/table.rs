@@ -0,0 +1,89 @@
+//! Tables that tell whether a number is among the inputs, for `logic.rs` to search in.
+
+/// A set of the input numbers, made for looking numbers up in as fast as possible.
+pub trait PresenceTable {
+    /// Returns whether `n` is among the numbers.
+    fn contains(&self, n: u32) -> bool;
+}
+
+/// One bit for each value up to the largest number, which is set if the value is among the numbers.
+///
+/// Lookups are a single memory access, but the table takes `max` / 8 bytes,
+/// so it is only worth it when the numbers are dense enough.
+/// For inputs up to 10^6 this is 125 KB, which mostly fits in the cache.
+#[derive(Clone, Debug)]
+pub struct BitSet {
+    words: Vec<u64>,
+}
+
+impl BitSet {
+    /// Creates a table of `numbers`, whose largest number is `max`.
+    #[must_use]
+    pub fn new(numbers: &[u32], max: u32) -> Self {
+        // `max` / 64 + 1 words hold the bits for `0..=max`, even if `max` is `u32::MAX`.
+        let mut words = vec![0; (max / 64) as usize + 1];
+
+        // Sorted numbers set the bits of one word after another,
+        // so collect the bits of a word before writing it, rather than writing every bit to memory.
+        let mut index = 0;
+        let mut word = 0;
+        for &n in numbers {
+            let n_index = (n / 64) as usize;
+            if n_index != index {
+                words[index] |= word;
+                (index, word) = (n_index, 0);
+            }
+            word |= 1 << (n % 64);
+        }
+        words[index] |= word;
+
+        Self { words }
+    }
+
+    /// Returns whether a [`BitSet`] is the better table for `len` numbers up to `max`.
+    ///
+    /// It is, unless it would be much larger than the numbers themselves and more than 1 MiB;
+    /// then the numbers are too sparse, and a [`Sorted`] table is used instead.
+    #[must_use]
+    pub fn is_better(len: usize, max: u32) -> bool {
+        // 2^23 bits are 1 MiB.
+        const ALWAYS_BELOW: u64 = 1 << 23;
+        // 256 bits are 8 times the memory of a `u32`.
+        const BITS_PER_NUMBER: u64 = 256;
+
+        let len = u64::try_from(len).unwrap_or(u64::MAX);
+        u64::from(max) < ALWAYS_BELOW.max(len.saturating_mul(BITS_PER_NUMBER))
+    }
+}
+
+impl PresenceTable for BitSet {
+    /// # Panics
+    ///
+    /// Panics if `n` is larger than the `max` that the table was created with.
+    #[inline]
+    fn contains(&self, n: u32) -> bool {
+        self.words[(n / 64) as usize] & (1 << (n % 64)) != 0
+    }
+}
+
+/// The sorted numbers themselves, searched with a binary search.
+///
+/// This takes no memory of its own whatever the numbers are, such as `[2, 3, 4294967295]`,
+/// but each lookup takes O(log(n)) time.
+#[derive(Clone, Copy, Debug)]
+pub struct Sorted<'a>(&'a [u32]);
+
+impl<'a> Sorted<'a> {
+    /// Creates a table of `numbers`, which must be sorted.
+    #[must_use]
+    pub const fn new(numbers: &'a [u32]) -> Self {
+        Self(numbers)
+    }
+}
+
+impl PresenceTable for Sorted<'_> {
+    #[inline]
+    fn contains(&self, n: u32) -> bool {
+        self.0.binary_search(&n).is_ok()
+    }
+}
@@ -1,10 +1,6 @@
 kickstart_macros::fn_run!(a6);
 
-#[test]
-fn sample() {
-    run("5\n2 4 8 11 12", "8\n");
-    run("4\n2 3 4 5", "-1\n");
-}
+kickstart_macros::sample_tests!(a6, "tests/samples");
 
 #[test]
 fn repeat() {
@@ -18,3 +14,121 @@ fn scrambled() {
     run("5\n11 8 2 4 12", "8\n");
     run("4\n5 4 3 2", "-1\n");
 }
+
+// Found by fuzzing, as an allocation of gigabytes.
+#[test]
+#[should_panic(expected = "Too many numbers")]
+fn too_many_numbers() {
+    run("3440230200\n", "");
+}
+
+// Found by fuzzing; `solve` used to run out of numbers without ever finding a larger `a`.
+#[test]
+#[should_panic(expected = "The numbers must be at least 2")]
+fn one() {
+    run("2\n1 1\n", "");
+}
+
+#[test]
+fn witness() {
+    assert_eq!(a6::solve_with_witness(&[2, 4, 8, 11, 12]), Some((2, 4, 8)));
+    assert_eq!(a6::solve_with_witness(&[2, 3, 4, 5]), None);
+    assert_eq!(
+        a6::solve_with_witness(&[2, 2, 4, 4, 16, 23]),
+        Some((4, 4, 16))
+    );
+    // 2 * 18 and 3 * 12 both give 36; the smaller `a` is returned.
+    assert_eq!(
+        a6::solve_with_witness(&[2, 3, 12, 18, 36]),
+        Some((2, 18, 36))
+    );
+}
+
+#[test]
+fn witness_output() {
+    use solution::Solution;
+
+    let run = |input: &str| {
+        let mut output = Vec::new();
+        a6::A6Witness::run(input.as_bytes(), &mut output);
+        String::from_utf8(output).unwrap()
+    };
+    assert_eq!(run("5\n11 8 2 4 12\n"), "2 4 8\n");
+    assert_eq!(run("4\n2 3 4 5\n"), "-1\n");
+}
+
+#[test]
+fn triples() {
+    let numbers = [2, 2, 3, 4, 6, 8, 12, 24];
+    let expected = [
+        (2, 12, 24),
+        (2, 6, 12),
+        (2, 4, 8),
+        (2, 3, 6),
+        (2, 2, 4),
+        (3, 8, 24),
+        (3, 4, 12),
+        (4, 6, 24),
+    ];
+    assert_eq!(a6::triples(&numbers), expected);
+    assert_eq!(a6::count_triples(&numbers), 8);
+
+    // `a == b` needs two copies.
+    assert_eq!(a6::triples(&[2, 4]), []);
+    assert_eq!(a6::triples(&[2, 2, 4]), [(2, 2, 4)]);
+    // More copies don't give more triples.
+    assert_eq!(a6::triples(&[2, 2, 2, 4, 4, 8, 8]), [(2, 4, 8), (2, 2, 4)]);
+    // Repeated `b`s, when iterating over them (method 1) and over the table (method 2).
+    assert_eq!(
+        a6::triples(&[2, 3, 3, 6, 50, 100]),
+        [(2, 50, 100), (2, 3, 6)]
+    );
+    assert_eq!(a6::triples(&[2, 3, 3, 3, 3, 3, 6]), [(2, 3, 6)]);
+    assert_eq!(a6::count_triples(&[2, 3, 5]), 0);
+    assert_eq!(a6::count_triples(&[]), 0);
+}
+
+#[test]
+fn huge() {
+    run("3\n65535 65537 4294967295\n", "4294967295\n");
+    run("3\n2 3 4294967295\n", "-1\n");
+
+    let numbers = [
+        2,
+        3,
+        65535,
+        65537,
+        1_431_655_765,
+        4_294_967_294,
+        4_294_967_295,
+    ];
+    assert_eq!(
+        a6::solve_with_witness(&numbers),
+        Some((3, 1_431_655_765, 4_294_967_295))
+    );
+    assert_eq!(
+        a6::triples(&numbers),
+        [
+            (3, 1_431_655_765, 4_294_967_295),
+            (65535, 65537, 4_294_967_295)
+        ]
+    );
+}
+
+#[test]
+fn tables() {
+    use a6::table::{BitSet, PresenceTable, Sorted};
+
+    let numbers = [2, 63, 64, 65, 1000, 4_294_967_295];
+    let bits = BitSet::new(&numbers, 4_294_967_295);
+    let sorted = Sorted::new(&numbers);
+    for n in (0..2000).chain([4_294_967_294, 4_294_967_295]) {
+        assert_eq!(bits.contains(n), numbers.contains(&n), "{n}");
+        assert_eq!(sorted.contains(n), numbers.contains(&n), "{n}");
+    }
+
+    // A bitset of every `u32` would take 512 MB for 6 numbers.
+    assert!(!BitSet::is_better(numbers.len(), 4_294_967_295));
+    assert!(BitSet::is_better(1_000_000, 1_000_000));
+    assert!(BitSet::is_better(3, 1_000_000));
+}
@@ -0,0 +1,101 @@
+use quickcheck::{Arbitrary, Gen};
+use solution::{Generated, Inputs};
+
+/// A few small numbers, so that products among them are common.
+struct Numbers;
+
+impl Inputs for Numbers {
+    type Value = Vec<u32>;
+
+    fn generate(g: &mut Gen) -> Self::Value {
+        let len = usize::arbitrary(g) % 8 + 1;
+        (0..len).map(|_| u32::arbitrary(g) % 40 + 2).collect()
+    }
+
+    fn is_valid(numbers: &Self::Value) -> bool {
+        !numbers.is_empty() && numbers.iter().all(|&n| n >= 2)
+    }
+}
+
+/// The fifth powers of [`Numbers`], which are too sparse for a bitset.
+///
+/// Products stay products, as `a^5 * b^5 = (a * b)^5`.
+/// Shrinking may give other numbers, which are just as valid.
+struct Powers;
+
+impl Inputs for Powers {
+    type Value = Vec<u32>;
+
+    fn generate(g: &mut Gen) -> Self::Value {
+        Numbers::generate(g).into_iter().map(|n| n.pow(5)).collect()
+    }
+
+    fn is_valid(numbers: &Self::Value) -> bool {
+        Numbers::is_valid(numbers)
+    }
+}
+
+#[test]
+fn stress() {
+    solution::stress::<a6::A6, Numbers>(1000);
+}
+
+#[test]
+fn stress_sparse() {
+    solution::stress::<a6::A6, Powers>(1000);
+}
+
+#[test]
+fn stress_witness() {
+    fn valid(Generated(mut numbers): Generated<Numbers>) -> bool {
+        let expected = a6::brute::solve(&numbers);
+        numbers.sort_unstable();
+        match a6::solve_with_witness(&numbers) {
+            None => expected.is_none(),
+            Some((a, b, c)) => {
+                let mut remaining = numbers;
+                let all_found = [a, b, c].into_iter().all(|n| {
+                    remaining
+                        .iter()
+                        .position(|&m| m == n)
+                        .map(|index| remaining.swap_remove(index))
+                        .is_some()
+                });
+                all_found && a <= b && a * b == c && Some(c) == expected
+            }
+        }
+    }
+
+    quickcheck::QuickCheck::new()
+        .tests(1000)
+        .quickcheck(valid as fn(Generated<Numbers>) -> bool);
+}
+
+#[test]
+fn stress_triples() {
+    fn agrees(Generated(mut numbers): Generated<Numbers>) -> bool {
+        let expected = a6::brute::triples(&numbers);
+        numbers.sort_unstable();
+        let mut found = a6::triples(&numbers);
+        found.sort_unstable();
+        found == expected && a6::count_triples(&numbers) == expected.len() as u64
+    }
+
+    quickcheck::QuickCheck::new()
+        .tests(1000)
+        .quickcheck(agrees as fn(Generated<Numbers>) -> bool);
+}
+
+#[cfg(feature = "parallel")]
+#[test]
+fn stress_parallel() {
+    fn agrees(Generated(mut numbers): Generated<Numbers>) -> bool {
+        let expected = a6::brute::solve(&numbers);
+        numbers.sort_unstable();
+        a6::solve_parallel(&numbers) == expected
+    }
+
+    quickcheck::QuickCheck::new()
+        .tests(1000)
+        .quickcheck(agrees as fn(Generated<Numbers>) -> bool);
+}
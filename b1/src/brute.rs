@@ -0,0 +1,16 @@
+use crate::Payment;
+
+/// Returns how the members of a team can pay for a problem set,
+/// by counting up the amount that each member pays until the cost is reached.
+#[must_use]
+pub fn payment(cost: u32, members: u32) -> Payment {
+    let mut amount_to_pay = 0;
+    while u64::from(amount_to_pay + 1) * u64::from(members) <= u64::from(cost) {
+        amount_to_pay += 1;
+    }
+    // Whatever is left over cannot be split between the members.
+    match cost - amount_to_pay * members {
+        0 => Payment::Even { amount_to_pay },
+        remainder => Payment::Uneven { remainder },
+    }
+}
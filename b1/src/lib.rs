@@ -1,29 +1,139 @@
-use std::io::prelude::*;
-
-pub fn main_impl(input: impl BufRead, mut output: impl Write) {
-    // Repeat the computation and printing for each pair of input.
-    for (cost, members) in input_cost_members(input) {
-        match (cost / members, cost % members) {
-            // If there is no remainder, the cost can be evenly split.
-            (amount_to_pay, 0) => writeln!(output, "YES {amount_to_pay}"),
-            // If there is some remainder, we can subtract that remainder
-            // so that the cost can be evenly split.
-            (_, remainder) => writeln!(output, "NO {remainder}"),
+pub mod brute;
+
+use std::io::{self, prelude::*};
+
+use solution::{BruteForce, Solution};
+use write_int::WriteInt;
+
+/// The solution to Problem B1.
+#[derive(Clone, Copy, Debug)]
+pub struct B1;
+
+/// How the members of a team can pay for a problem set.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Payment {
+    /// The cost can be evenly split, with each member paying `amount_to_pay`.
+    Even { amount_to_pay: u32 },
+    /// The cost can only be evenly split after subtracting `remainder` from it.
+    Uneven { remainder: u32 },
+}
+
+/// A test case of Problem B1.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, read_u32::KickstartInput)]
+pub struct TestCase {
+    /// The cost of the problem set.
+    #[sep = "space"]
+    pub cost: u32,
+    /// The number of members in the team.
+    #[line]
+    pub members: u32,
+}
+
+impl From<(u32, u32)> for TestCase {
+    fn from((cost, members): (u32, u32)) -> Self {
+        Self { cost, members }
+    }
+}
+
+impl Solution for B1 {
+    const NAME: &'static str = "B1";
+    const DESCRIPTION: &'static str = env!("CARGO_PKG_DESCRIPTION");
+
+    type Input = Vec<TestCase>;
+    type Output = Vec<Payment>;
+
+    fn parse(input: impl BufRead) -> Self::Input {
+        input_cost_members(input)
+    }
+
+    fn solve(test_cases: Self::Input) -> Self::Output {
+        // Repeat the computation for each pair of input.
+        test_cases
+            .into_iter()
+            .map(
+                |TestCase { cost, members }| match (cost / members, cost % members) {
+                    // If there is no remainder, the cost can be evenly split.
+                    (amount_to_pay, 0) => Payment::Even { amount_to_pay },
+                    // If there is some remainder, we can subtract that remainder
+                    // so that the cost can be evenly split.
+                    (_, remainder) => Payment::Uneven { remainder },
+                },
+            )
+            .collect()
+    }
+
+    fn format(payments: &Self::Output, mut writer: impl Write) -> io::Result<()> {
+        for payment in payments {
+            match *payment {
+                Payment::Even { amount_to_pay } => {
+                    writer.write_all(b"YES ")?;
+                    writer.write_u32(amount_to_pay)?;
+                }
+                Payment::Uneven { remainder } => {
+                    writer.write_all(b"NO ")?;
+                    writer.write_u32(remainder)?;
+                }
+            }
+            writer.write_newline()?;
         }
-        .expect("An output erro occured");
+        Ok(())
+    }
+}
+
+impl BruteForce for B1 {
+    fn brute_force(test_cases: Self::Input) -> Self::Output {
+        test_cases
+            .into_iter()
+            .map(|TestCase { cost, members }| brute::payment(cost, members))
+            .collect()
     }
 }
 
+/// Reads the number of test cases from `input`, then a cost and a team size on each line,
+/// and writes whether each cost splits evenly to `output`, one per line.
+///
+/// Each line is `YES` and the share of each member, or `NO` and the remainder of the cost.
+///
+/// # Panics
+///
+/// Panics if the input is malformed, a team size is 0, or if any I/O error occured.
+pub fn main_impl(input: impl BufRead, output: impl Write) {
+    B1::run(input, output);
+}
+
+/// Checks that `input` follows the input format of Problem B1 exactly.
+///
+/// # Errors
+///
+/// Returns an error describing the first violation of the input format.
+pub fn validate(input: impl BufRead) -> Result<(), read_u32::ValidationError> {
+    let mut validator = read_u32::Validator::new(input);
+    let test_case_count = validator.expect_u32_in(1..=solution::MAX_TEST_CASES)?;
+    validator.expect_eoln()?;
+    for _ in 0..test_case_count {
+        validator.expect_u32_in(1..=1_000_000)?; // cost
+        validator.expect_space()?;
+        validator.expect_u32_in(1..=1_000_000)?; // members
+        validator.expect_eoln()?;
+    }
+    validator.expect_eof()
+}
+
 // Input routine.
-fn input_cost_members(input: impl BufRead) -> impl Iterator<Item = (u32, u32)> {
-    let mut reader = read_u32::U32Reader::new(input);
+#[derive(read_u32::KickstartInput)]
+struct Input {
+    #[count_prefixed]
+    test_cases: Vec<TestCase>,
+}
 
-    let test_case_count = reader.read_until_newline();
+fn input_cost_members(input: impl BufRead) -> Vec<TestCase> {
+    let mut reader = read_u32::U32Reader::with_mode(input, read_u32::Mode::from_env());
+    let test_cases = <Input as read_u32::KickstartInput>::read_from(&mut reader).test_cases;
 
-    std::iter::repeat_with(move || {
-        let cost = reader.read_until_space();
-        let members = reader.read_until_newline();
-        (cost, members)
-    })
-    .take(test_case_count as usize)
+    // The cost is divided by the number of members.
+    assert!(
+        test_cases.iter().all(|test_case| test_case.members != 0),
+        "A team must have at least one member"
+    );
+    test_cases
 }
@@ -1 +1 @@
-kickstart_macros::fn_main!(b1);
+kickstart_macros::fn_main!(b1, slurp);
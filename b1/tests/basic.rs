@@ -1,9 +1,6 @@
 kickstart_macros::fn_run!(b1);
 
-#[test]
-fn sample() {
-    run("2\n20 5\n31 6", "YES 4\nNO 1\n");
-}
+kickstart_macros::sample_tests!(b1, "tests/samples");
 
 // When the price of the prize is very low.
 #[test]
@@ -19,3 +16,10 @@ fn expensive() {
         "YES 1000000\nNO 1\nYES 1\n",
     );
 }
+
+// Found by fuzzing, as a division by zero.
+#[test]
+#[should_panic(expected = "A team must have at least one member")]
+fn no_members() {
+    run("1\n13 0\n", "");
+}
@@ -0,0 +1,30 @@
+use quickcheck::{Arbitrary, Gen};
+use solution::{Cases, Inputs};
+
+/// A few test cases with small costs, split between teams that are sometimes larger than the cost.
+struct TestCases;
+
+impl Inputs for TestCases {
+    type Value = Cases<(u32, u32)>;
+
+    fn generate(g: &mut Gen) -> Self::Value {
+        let len = usize::arbitrary(g) % 5 + 1;
+        Cases(
+            (0..len)
+                .map(|_| (u32::arbitrary(g) % 1000 + 1, u32::arbitrary(g) % 50 + 1))
+                .collect(),
+        )
+    }
+
+    fn is_valid(Cases(test_cases): &Self::Value) -> bool {
+        !test_cases.is_empty()
+            && test_cases
+                .iter()
+                .all(|&(cost, members)| cost >= 1 && members >= 1)
+    }
+}
+
+#[test]
+fn stress() {
+    solution::stress::<b1::B1, TestCases>(1000);
+}
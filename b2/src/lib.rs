@@ -1,24 +1,83 @@
 mod logic;
 
-use std::io::prelude::*;
-
-pub fn main_impl(input: impl BufRead, mut output: impl Write) {
-    // Check that every input letter is a letter that can be put on the sign.
-    if logic::is_sign(input_letters(input)) {
-        writeln!(output, "YES")
-    } else {
-        writeln!(output, "NO")
+use std::io::{self, prelude::*};
+
+use solution::Solution;
+use write_int::WriteInt;
+
+/// The solution to Problem B2.
+#[derive(Clone, Copy, Debug)]
+pub struct B2;
+
+impl Solution for B2 {
+    const NAME: &'static str = "B2";
+    const DESCRIPTION: &'static str = env!("CARGO_PKG_DESCRIPTION");
+
+    type Input = Vec<u8>;
+    type Output = bool;
+
+    fn parse(input: impl BufRead) -> Self::Input {
+        let mut reader = read_u32::U32Reader::with_mode(input, read_u32::Mode::from_env());
+        input_letters(&mut reader).collect()
+    }
+
+    fn solve(letters: Self::Input) -> Self::Output {
+        // Check that every input letter is a letter that can be put on the sign.
+        logic::is_sign(letters)
+    }
+
+    fn format(is_sign: &Self::Output, mut writer: impl Write) -> io::Result<()> {
+        writer.write_yes_no(*is_sign)?;
+        writer.write_newline()
+    }
+}
+
+/// Reads a word of capital letters from `input` and writes `YES` to `output`
+/// if every letter looks the same when rotated, or `NO` otherwise.
+///
+/// # Panics
+///
+/// Panics if any I/O error occured.
+pub fn main_impl(input: impl BufRead, output: impl Write) {
+    B2::run(input, output);
+}
+
+/// Checks that `input` follows the input format of Problem B2 exactly.
+///
+/// # Errors
+///
+/// Returns an error describing the first violation of the input format.
+pub fn validate(input: impl BufRead) -> Result<(), read_u32::ValidationError> {
+    let mut validator = read_u32::Validator::new(input);
+    let start = validator.position();
+    let letters = validator.expect_token()?;
+    if let Some(offset) = letters
+        .iter()
+        .position(|letter| !letter.is_ascii_uppercase())
+    {
+        return Err(read_u32::ValidationError::invalid(
+            "the letters must all be capital letters",
+            start.shifted(offset),
+        ));
     }
-    .expect("An output error occured");
+    validator.expect_eoln()?;
+    validator.expect_eof()
 }
 
 // Input routine.
-fn input_letters(input: impl BufRead) -> impl Iterator<Item = u8> {
-    input
-        // Because the input is a string of "all capital letters",
-        // we can just iterate through the bytes.
-        .bytes()
-        .map(|byte| byte.expect("An input error occured"))
-        // If it is not a capital letter, we might have reached a newline character; it means we're done.
-        .take_while(|&byte| byte.is_ascii_uppercase())
+fn input_letters<R: BufRead>(reader: &mut read_u32::U32Reader<R>) -> impl Iterator<Item = u8> + '_ {
+    // Because the input is a string of "all capital letters",
+    // we can just iterate through the bytes of the line.
+    // In lenient mode, the word may be surrounded by any whitespace, so skip to the word first.
+    let letters = match reader.mode() {
+        read_u32::Mode::Strict => reader.try_read_line_bytes(),
+        read_u32::Mode::Lenient => reader.try_read_token(),
+    };
+    // An empty line or input has no letters, which can always be put on the sign.
+    let letters = match letters {
+        Err(read_u32::ReadError::UnexpectedEof { .. }) => &[],
+        result => result.unwrap_or_else(|error| panic!("Failed to read the letters: {error}")),
+    };
+    // If it is not a capital letter, we might have reached trailing whitespace; it means we're done.
+    letters.iter().copied().take_while(u8::is_ascii_uppercase)
 }
@@ -1,9 +1,12 @@
 kickstart_macros::fn_run!(b2);
 
+kickstart_macros::sample_tests!(b2, "tests/samples");
+
 #[test]
-fn sample() {
-    run("IN", "YES\n");
+fn line_endings() {
     run("IN\n", "YES\n");
+    run("\n", "YES\n");
+    run("IN \n", "YES\n");
 }
 
 #[test]
@@ -0,0 +1,21 @@
+//! Runs the samples in `tests/lenient` in lenient mode.
+//!
+//! This file is a test binary of its own, so setting the mode here doesn't affect the other tests.
+
+kickstart_macros::fn_run!(b2);
+
+#[test]
+fn samples() {
+    std::env::set_var(read_u32::Mode::ENV_VAR, "1");
+
+    // The word is judged, rather than the blanks before it.
+    run(
+        include_str!("lenient/leading_blanks.in"),
+        include_str!("lenient/leading_blanks.out"),
+    );
+    // Blank lines have no letters, like an empty input.
+    run(
+        include_str!("lenient/blank.in"),
+        include_str!("lenient/blank.out"),
+    );
+}
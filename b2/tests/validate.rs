@@ -0,0 +1,21 @@
+use read_u32::{Position, ValidationError};
+
+#[test]
+fn valid() {
+    b2::validate(&b"SOS\n"[..]).unwrap();
+}
+
+#[test]
+fn invalid_letter() {
+    // The error points at the first letter that is not a capital letter.
+    let error = b2::validate(&b"NOoN\n"[..]).unwrap_err();
+    assert!(matches!(error, ValidationError::Invalid { .. }));
+    assert_eq!(
+        error.position(),
+        Position {
+            offset: 2,
+            line: 1,
+            column: 3,
+        }
+    );
+}
@@ -0,0 +1,20 @@
+/// Returns the minimum total distance required to walk to the chat position,
+/// by trying every position between the classrooms.
+#[must_use]
+pub fn min_total_distance(classrooms: [u32; 3]) -> u32 {
+    let mut sorted = classrooms;
+    sorted.sort_unstable();
+    let [first, _, last] = sorted;
+
+    let distance = (first..=last)
+        .map(|chat| {
+            classrooms
+                .iter()
+                .map(|&classroom| u64::from(classroom.abs_diff(chat)))
+                .sum::<u64>()
+        })
+        .min()
+        .unwrap_or_default();
+    // Chatting in the middle classroom takes `last - first`, which fits.
+    u32::try_from(distance).unwrap_or(u32::MAX)
+}
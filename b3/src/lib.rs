@@ -1,19 +1,70 @@
+pub mod brute;
 mod logic;
 
-use std::io::prelude::*;
+use std::io::{self, prelude::*};
 
-pub fn main_impl(input: impl BufRead, mut output: impl Write) {
-    writeln!(
-        output,
-        "{min_total_distance}",
-        min_total_distance = logic::min_total_distance(input_positions(input))
-    )
-    .expect("An output error occured");
+use solution::{BruteForce, Solution};
+
+/// The solution to Problem B3.
+#[derive(Clone, Copy, Debug)]
+pub struct B3;
+
+impl Solution for B3 {
+    const NAME: &'static str = "B3";
+    const DESCRIPTION: &'static str = env!("CARGO_PKG_DESCRIPTION");
+
+    type Input = [u32; 3];
+    type Output = u32;
+
+    fn parse(input: impl BufRead) -> Self::Input {
+        input_positions(input)
+    }
+
+    fn solve(positions: Self::Input) -> Self::Output {
+        logic::min_total_distance(positions)
+    }
+
+    fn format(min_total_distance: &Self::Output, mut writer: impl Write) -> io::Result<()> {
+        writeln!(writer, "{min_total_distance}")
+    }
+}
+
+impl BruteForce for B3 {
+    fn brute_force(positions: Self::Input) -> Self::Output {
+        brute::min_total_distance(positions)
+    }
+}
+
+/// Reads the positions of three classrooms on one line from `input`
+/// and writes the shortest total walk to a common position to `output`.
+///
+/// # Panics
+///
+/// Panics if the input is malformed or if any I/O error occured.
+pub fn main_impl(input: impl BufRead, output: impl Write) {
+    B3::run(input, output);
+}
+
+/// Checks that `input` follows the input format of Problem B3 exactly.
+///
+/// # Errors
+///
+/// Returns an error describing the first violation of the input format.
+pub fn validate(input: impl BufRead) -> Result<(), read_u32::ValidationError> {
+    let mut validator = read_u32::Validator::new(input);
+    // The statement doesn't bound the classroom numbers further.
+    validator.expect_u32_in(0..=u32::MAX)?; // Alice
+    validator.expect_space()?;
+    validator.expect_u32_in(0..=u32::MAX)?; // Bob
+    validator.expect_space()?;
+    validator.expect_u32_in(0..=u32::MAX)?; // Charlie
+    validator.expect_eoln()?;
+    validator.expect_eof()
 }
 
 // Input routine.
 fn input_positions(input: impl BufRead) -> [u32; 3] {
-    let mut reader = read_u32::U32Reader::new(input);
+    let mut reader = read_u32::U32Reader::with_mode(input, read_u32::Mode::from_env());
     [
         reader.read_until_space(),   // Alice
         reader.read_until_space(),   // Bob
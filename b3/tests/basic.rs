@@ -1,10 +1,6 @@
 kickstart_macros::fn_run!(b3);
 
-#[test]
-fn samples() {
-    run("7 1 4", "6\n");
-    run("8 1 9", "8\n");
-}
+kickstart_macros::sample_tests!(b3, "tests/samples");
 
 #[test]
 fn same_place() {
@@ -0,0 +1,27 @@
+use quickcheck::{Arbitrary, Gen};
+use solution::Inputs;
+
+/// Three classrooms close to each other,
+/// either near the start or near the end of the range of `u32`.
+struct Classrooms;
+
+impl Inputs for Classrooms {
+    // A tuple rather than an array, which `quickcheck` can shrink.
+    type Value = (u32, u32, u32);
+
+    fn generate(g: &mut Gen) -> Self::Value {
+        let base = *g.choose(&[0, u32::MAX - 50]).unwrap();
+        [(); 3].map(|()| base + u32::arbitrary(g) % 50).into()
+    }
+
+    fn is_valid(&classrooms: &Self::Value) -> bool {
+        // Shrinking towards 0 keeps the classrooms close only if they are all near it.
+        let classrooms = <[u32; 3]>::from(classrooms);
+        classrooms.iter().max().unwrap() - classrooms.iter().min().unwrap() < 50
+    }
+}
+
+#[test]
+fn stress() {
+    solution::stress::<b3::B3, Classrooms>(1000);
+}
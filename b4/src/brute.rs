@@ -0,0 +1,18 @@
+/// Returns the most emeralds one can get,
+/// by trying every sequence of trades with dynamic programming.
+#[must_use]
+pub fn emeralds(sticks: u32, diamonds: u32) -> u32 {
+    let (sticks, diamonds) = (sticks as usize, diamonds as usize);
+
+    // `best[s][d]` is the most emeralds one can get from `s` sticks and `d` diamonds.
+    let mut best = vec![vec![0; diamonds + 1]; sticks + 1];
+    for s in 0..=sticks {
+        for d in 0..=diamonds {
+            // A shovel takes 2 sticks and 1 diamond; a sword takes 1 stick and 2 diamonds.
+            let shovel = (s >= 2 && d >= 1).then(|| best[s - 2][d - 1] + 1);
+            let sword = (s >= 1 && d >= 2).then(|| best[s - 1][d - 2] + 1);
+            best[s][d] = shovel.max(sword).unwrap_or(0);
+        }
+    }
+    best[sticks][diamonds]
+}
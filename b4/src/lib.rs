@@ -1,26 +1,116 @@
+pub mod brute;
 mod logic;
 
-use std::io::prelude::*;
+use std::io::{self, prelude::*};
 
-pub fn main_impl(input: impl BufRead, mut output: impl Write) {
-    for (sticks, diamonds) in input_sticks_diamonds(input) {
-        writeln!(
-            output,
-            "{emeralds}",
-            emeralds = logic::emeralds(sticks, diamonds)
-        )
-        .expect("An output error occured");
+use solution::{BruteForce, Solution};
+use write_int::WriteInt;
+
+/// The solution to Problem B4.
+#[derive(Clone, Copy, Debug)]
+pub struct B4;
+
+/// A test case of Problem B4.
+// The "sticks" value is terminated by a whitespace character
+// while the "diamonds" value is terminated by a newline character.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, read_u32::KickstartInput)]
+pub struct TestCase {
+    /// The number of sticks.
+    #[sep = "space"]
+    pub sticks: u32,
+    /// The number of diamonds.
+    #[line]
+    pub diamonds: u32,
+}
+
+impl From<(u32, u32)> for TestCase {
+    fn from((sticks, diamonds): (u32, u32)) -> Self {
+        Self { sticks, diamonds }
     }
 }
 
+impl Solution for B4 {
+    const NAME: &'static str = "B4";
+    const DESCRIPTION: &'static str = env!("CARGO_PKG_DESCRIPTION");
+
+    type Input = Vec<TestCase>;
+    type Output = Vec<u32>;
+
+    fn parse(input: impl BufRead) -> Self::Input {
+        input_sticks_diamonds(input)
+    }
+
+    fn solve(test_cases: Self::Input) -> Self::Output {
+        test_cases
+            .into_iter()
+            .map(|TestCase { sticks, diamonds }| logic::emeralds(sticks, diamonds))
+            .collect()
+    }
+
+    fn format(emeralds: &Self::Output, mut writer: impl Write) -> io::Result<()> {
+        for &emeralds in emeralds {
+            writer.write_u32(emeralds)?;
+            writer.write_newline()?;
+        }
+        Ok(())
+    }
+}
+
+impl BruteForce for B4 {
+    fn brute_force(test_cases: Self::Input) -> Self::Output {
+        test_cases
+            .into_iter()
+            .map(|TestCase { sticks, diamonds }| brute::emeralds(sticks, diamonds))
+            .collect()
+    }
+}
+
+/// Reads the number of test cases from `input`, then the sticks and diamonds on each line,
+/// and writes the most emeralds that each can be traded for to `output`, one per line.
+///
+/// # Panics
+///
+/// Panics if the input is malformed, there are more than 1000000000 sticks or diamonds,
+/// or if any I/O error occured.
+pub fn main_impl(input: impl BufRead, output: impl Write) {
+    B4::run(input, output);
+}
+
+/// Checks that `input` follows the input format of Problem B4 exactly.
+///
+/// # Errors
+///
+/// Returns an error describing the first violation of the input format.
+pub fn validate(input: impl BufRead) -> Result<(), read_u32::ValidationError> {
+    let mut validator = read_u32::Validator::new(input);
+    let test_case_count = validator.expect_u32_in(1..=solution::MAX_TEST_CASES)?;
+    validator.expect_eoln()?;
+    for _ in 0..test_case_count {
+        validator.expect_u32_in(0..=1_000_000_000)?; // sticks
+        validator.expect_space()?;
+        validator.expect_u32_in(0..=1_000_000_000)?; // diamonds
+        validator.expect_eoln()?;
+    }
+    validator.expect_eof()
+}
+
 // Input routine.
-fn input_sticks_diamonds(input: impl BufRead) -> impl Iterator<Item = (u32, u32)> {
-    let mut reader = read_u32::U32Reader::new(input);
+#[derive(read_u32::KickstartInput)]
+struct Input {
+    #[count_prefixed]
+    test_cases: Vec<TestCase>,
+}
 
-    let test_case_count = reader.read_until_newline();
+fn input_sticks_diamonds(input: impl BufRead) -> Vec<TestCase> {
+    let mut reader = read_u32::U32Reader::with_mode(input, read_u32::Mode::from_env());
+    let test_cases = <Input as read_u32::KickstartInput>::read_from(&mut reader).test_cases;
 
-    // The "sticks" value is terminated by a whitespace character
-    // while the "diamonds" value is terminated by a newline character.
-    std::iter::repeat_with(move || (reader.read_until_space(), reader.read_until_newline()))
-        .take(test_case_count as usize)
+    // `emeralds` doubles the number of pairs, which only fits in a `u32` within the constraints.
+    for &TestCase { sticks, diamonds } in &test_cases {
+        assert!(
+            sticks.max(diamonds) <= 1_000_000_000,
+            "There must be at most 1000000000 sticks and diamonds"
+        );
+    }
+    test_cases
 }
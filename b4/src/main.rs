@@ -1 +1 @@
-kickstart_macros::fn_main!(b4);
+kickstart_macros::fn_main!(b4, slurp);
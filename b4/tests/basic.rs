@@ -1,11 +1,15 @@
 kickstart_macros::fn_run!(b4);
 
-#[test]
-fn sample() {
-    run("4\n4 4\n1000000000 0\n7 15\n8 7", "2\n0\n7\n5\n");
-}
+kickstart_macros::sample_tests!(b4, "tests/samples");
 
 #[test]
 fn zero() {
     run("3\n0 0\n0 1\n1 0", "0\n0\n0\n");
 }
+
+// Doubling this many pairs used to overflow.
+#[test]
+#[should_panic(expected = "There must be at most 1000000000 sticks and diamonds")]
+fn too_many() {
+    run("1\n3000000000 3000000000\n", "");
+}
@@ -0,0 +1,27 @@
+use quickcheck::{Arbitrary, Gen};
+use solution::{Cases, Inputs};
+
+/// A few test cases with a small number of sticks and diamonds.
+struct TestCases;
+
+impl Inputs for TestCases {
+    type Value = Cases<(u32, u32)>;
+
+    fn generate(g: &mut Gen) -> Self::Value {
+        let len = usize::arbitrary(g) % 5 + 1;
+        Cases(
+            (0..len)
+                .map(|_| (u32::arbitrary(g) % 50, u32::arbitrary(g) % 50))
+                .collect(),
+        )
+    }
+
+    fn is_valid(Cases(test_cases): &Self::Value) -> bool {
+        !test_cases.is_empty()
+    }
+}
+
+#[test]
+fn stress() {
+    solution::stress::<b4::B4, TestCases>(1000);
+}
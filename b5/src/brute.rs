@@ -0,0 +1,17 @@
+/// Returns whether the 9x9 sudoku board, with numbers from 0 to 8, is valid,
+/// by sorting the numbers of every row, column and box.
+#[must_use]
+pub fn is_valid_board(board: &[[u8; 9]; 9]) -> bool {
+    let rows = (0..9).map(|row| (0..9).map(|column| board[row][column]).collect());
+    let columns = (0..9).map(|column| (0..9).map(|row| board[row][column]).collect());
+    let boxes = (0..9).map(|index| {
+        (0..9)
+            .map(|cell| board[index / 3 * 3 + cell / 3][index % 3 * 3 + cell % 3])
+            .collect()
+    });
+
+    rows.chain(columns).chain(boxes).all(|mut group: Vec<u8>| {
+        group.sort_unstable();
+        group == [0, 1, 2, 3, 4, 5, 6, 7, 8]
+    })
+}
@@ -1,63 +1,115 @@
-use std::io::prelude::*;
+use std::io::{self, prelude::*};
 
+use solution::{BruteForce, Solution};
+
+pub mod brute;
 mod logic;
 
-pub fn main_impl(input: impl BufRead, mut output: impl Write) {
-    match input_sudoku_board(input) {
-        Some(board) if logic::is_valid_board(&board) => {
-            writeln!(output, "1")
-        }
-        _invalid => {
-            writeln!(output, "0")
+/// The solution to Problem B5.
+#[derive(Clone, Copy, Debug)]
+pub struct B5;
+
+impl Solution for B5 {
+    const NAME: &'static str = "B5";
+    const DESCRIPTION: &'static str = env!("CARGO_PKG_DESCRIPTION");
+
+    type Input = Option<[[u8; 9]; 9]>;
+    type Output = bool;
+
+    fn parse(input: impl BufRead) -> Self::Input {
+        input_sudoku_board(input)
+    }
+
+    fn solve(board: Self::Input) -> Self::Output {
+        // A board that is not even in the right format is not valid.
+        board.is_some_and(|board| logic::is_valid_board(&board))
+    }
+
+    fn format(is_valid: &Self::Output, mut writer: impl Write) -> io::Result<()> {
+        writeln!(writer, "{valid}", valid = u8::from(*is_valid))
+    }
+}
+
+impl BruteForce for B5 {
+    fn brute_force(board: Self::Input) -> Self::Output {
+        board.is_some_and(|board| brute::is_valid_board(&board))
+    }
+}
+
+/// Reads the size of a board and then its rows of digits from `input`,
+/// and writes `1` to `output` if it is a valid 9x9 sudoku, or `0` otherwise.
+///
+/// Malformed boards are invalid sudokus, so only I/O errors are errors.
+///
+/// # Panics
+///
+/// Panics if any I/O error occured.
+pub fn main_impl(input: impl BufRead, output: impl Write) {
+    B5::run(input, output);
+}
+
+/// Checks that `input` follows the input format of Problem B5 exactly.
+///
+/// # Errors
+///
+/// Returns an error describing the first violation of the input format.
+pub fn validate(input: impl BufRead) -> Result<(), read_u32::ValidationError> {
+    let mut validator = read_u32::Validator::new(input);
+    let width = validator.expect_u32_in(1..=9)?;
+    validator.expect_space()?;
+    let height = validator.expect_u32_in(1..=9)?;
+    validator.expect_eoln()?;
+    for _ in 0..height {
+        // Cells may contain anything printable; `main_impl` decides whether they are valid.
+        let position = validator.position();
+        let row = validator.expect_token()?;
+        if row.len() != width as usize || !row.iter().all(u8::is_ascii_graphic) {
+            return Err(read_u32::ValidationError::invalid(
+                format!("the row must contain exactly {width} printable characters"),
+                position,
+            ));
         }
+        validator.expect_eoln()?;
     }
-    .expect("An output error occured");
+    validator.expect_eof()
 }
 
 // Input routine.
 // Returns a two-dimensional array containing numbers from 0 to 8.
 //
 // This part completes all validation required for Subtask 1.
-fn input_sudoku_board(mut input: impl BufRead) -> Option<[[u8; 9]; 9]> {
-    // An input buffer.
-    let mut buf = Vec::new();
-
-    // Read the first line into the buffer.
-    input
-        .read_until(b'\n', &mut buf)
-        .expect("An input error occured");
+fn input_sudoku_board(input: impl BufRead) -> Option<[[u8; 9]; 9]> {
+    let mut reader = read_u32::U32Reader::with_mode(input, read_u32::Mode::from_env());
 
     // The first line, which contains the width and height, must be "9 9" to be valid.
-    match buf.as_slice() {
-        b"9 9\n" | b"9 9\r\n" => {}
-        _ => return None,
+    if ok_or_invalid(reader.try_read_line_bytes())? != b"9 9" {
+        return None;
     }
 
     let mut result_array = [[0u8; 9]; 9];
 
-    // Read the following 9 lines of 9 characters into the buffer.
+    // Read the following 9 lines of exactly 9 characters.
     for row in &mut result_array {
-        // Read one row into the buffer; it is guaranteed to have 9 characters.
-        buf.clear();
-        input
-            .read_until(b'\n', &mut buf)
-            .expect("An input error occured");
-
-        // Check that the first 9 bytes are valid ASCII digits from '1' to '9'.
-        for (cell, &byte) in std::iter::zip(row, &buf) {
+        let bytes = ok_or_invalid(reader.try_read_fixed_row::<9>())?;
+
+        // Check that the bytes are valid ASCII digits from '1' to '9'.
+        for (cell, &byte) in std::iter::zip(row, bytes) {
             match byte {
                 // Subtracting the digit by b'1' gives a number from 0 to 8 (corresponding to '1' to '9').
                 digit @ b'1'..=b'9' => *cell = digit - b'1',
                 _other => return None,
             }
         }
-
-        // If there are any additional bytes, this is an incorrect row.
-        match &buf[9..] {
-            b"" | b"\n" | b"\r\n" => {}
-            _additional => return None,
-        }
     }
 
     Some(result_array)
 }
+
+// Any malformed input means that the board is invalid,
+// but an I/O error is still a reason to panic.
+fn ok_or_invalid<T>(result: Result<T, read_u32::ReadError>) -> Option<T> {
+    match result {
+        Err(error @ read_u32::ReadError::Io { .. }) => panic!("An input error occured: {error}"),
+        result => result.ok(),
+    }
+}
@@ -0,0 +1,63 @@
+use quickcheck::{Arbitrary, Gen};
+use solution::Inputs;
+
+/// A board of numbers from 0 to 8, which `quickcheck` cannot make out of arrays by itself.
+#[derive(Clone, Debug)]
+struct Board([[u8; 9]; 9]);
+
+impl Arbitrary for Board {
+    // Only used by `quickcheck` itself; `Boards` makes the boards to test.
+    fn arbitrary(g: &mut Gen) -> Self {
+        Self([(); 9].map(|()| [(); 9].map(|()| u8::arbitrary(g) % 9)))
+    }
+
+    // A board has a fixed size, so it doesn't shrink.
+}
+
+impl From<Board> for Option<[[u8; 9]; 9]> {
+    fn from(Board(board): Board) -> Self {
+        Some(board)
+    }
+}
+
+/// Shuffled valid boards, with a cell changed in half of them.
+///
+/// Random boards are almost never valid, so they would not test much.
+struct Boards;
+
+impl Inputs for Boards {
+    type Value = Board;
+
+    fn generate(g: &mut Gen) -> Board {
+        // Shuffling the numbers, the rows within each band and the columns within each stack
+        // keeps a valid board valid.
+        let mut numbers: Vec<u8> = (0..9).collect();
+        numbers.sort_by_key(|_| u8::arbitrary(g));
+        let order = |g: &mut Gen| {
+            let mut order: Vec<usize> = (0..9).collect();
+            for band in order.chunks_mut(3) {
+                band.sort_by_key(|_| u8::arbitrary(g));
+            }
+            order
+        };
+        let (rows, columns) = (order(g), order(g));
+
+        let mut board = [[0; 9]; 9];
+        for (row, &from_row) in board.iter_mut().zip(&rows) {
+            for (cell, &from_column) in row.iter_mut().zip(&columns) {
+                *cell = numbers[(from_row * 3 + from_row / 3 + from_column) % 9];
+            }
+        }
+
+        if bool::arbitrary(g) {
+            let cell = &mut board[usize::arbitrary(g) % 9][usize::arbitrary(g) % 9];
+            *cell = u8::arbitrary(g) % 9;
+        }
+        Board(board)
+    }
+}
+
+#[test]
+fn stress() {
+    solution::stress::<b5::B5, Boards>(1000);
+}
@@ -0,0 +1,4 @@
+fn main() {
+    // Regenerate the sample tests when samples are added or removed.
+    println!("cargo:rerun-if-changed=tests/samples");
+}
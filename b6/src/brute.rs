@@ -0,0 +1,24 @@
+/// Calculates the minimum distances that all students need to walk,
+/// by finding the nearest collection point for each classroom.
+///
+/// # Panics
+///
+/// Panics if there is no collection point among the classrooms.
+pub fn minimum_distance(classrooms: impl IntoIterator<Item = bool>) -> u64 {
+    let classrooms: Vec<bool> = classrooms.into_iter().collect();
+    let collection_points: Vec<usize> = (0..classrooms.len()).filter(|&i| classrooms[i]).collect();
+    assert!(
+        !collection_points.is_empty(),
+        "There is no collection point among all classrooms"
+    );
+
+    (0..classrooms.len())
+        .map(|i| {
+            collection_points
+                .iter()
+                .map(|&point| i.abs_diff(point) as u64)
+                .min()
+                .unwrap()
+        })
+        .sum()
+}
@@ -1,83 +1,133 @@
-use std::io::prelude::*;
+use std::io::{self, prelude::*};
 
+use solution::{BruteForce, Solution};
+use write_int::WriteInt;
+
+pub mod brute;
 mod logic;
 
 // for direct testing
 pub use logic::minimum_distance;
 
-pub fn main_impl(input: impl BufRead, mut output: impl Write) {
-    let mut test_cases = input_classrooms(input);
+/// The solution to Problem B6.
+#[derive(Clone, Copy, Debug)]
+pub struct B6;
+
+impl Solution for B6 {
+    const NAME: &'static str = "B6";
+    const DESCRIPTION: &'static str = env!("CARGO_PKG_DESCRIPTION");
+
+    type Input = Vec<Vec<bool>>;
+    type Output = Vec<u64>;
+
+    fn parse(input: impl BufRead) -> Self::Input {
+        let mut test_cases = input_classrooms(input);
+        std::iter::from_fn(|| Some(test_cases.next_test_case()?.collect())).collect()
+    }
+
+    fn solve(test_cases: Self::Input) -> Self::Output {
+        test_cases.into_iter().map(minimum_distance).collect()
+    }
+
+    fn format(distances: &Self::Output, mut writer: impl Write) -> io::Result<()> {
+        for &distance in distances {
+            writer.write_u64(distance)?;
+            writer.write_newline()?;
+        }
+        Ok(())
+    }
+}
 
-    while let Some(classrooms) = test_cases.next_test_case() {
-        writeln!(
-            output,
-            "{distance}",
-            distance = minimum_distance(classrooms)
-        )
-        .expect("An output error occured");
+impl BruteForce for B6 {
+    fn brute_force(test_cases: Self::Input) -> Self::Output {
+        test_cases
+            .into_iter()
+            .map(brute::minimum_distance)
+            .collect()
     }
 }
 
+/// Reads rows of classrooms from `input` and writes the shortest total walk to collection points
+/// in each row to `output`, one per line.
+///
+/// The input is the number of test cases, then for each the number of classrooms on one line
+/// and a row of `0` and `1` on the next, where `1` marks a classroom with a collection point.
+///
+/// # Panics
+///
+/// Panics if the input is malformed or if any I/O error occured.
+pub fn main_impl(input: impl BufRead, output: impl Write) {
+    B6::run(input, output);
+}
+
+/// Checks that `input` follows the input format of Problem B6 exactly.
+///
+/// # Errors
+///
+/// Returns an error describing the first violation of the input format.
+pub fn validate(input: impl BufRead) -> Result<(), read_u32::ValidationError> {
+    let mut validator = read_u32::Validator::new(input);
+    let test_case_count = validator.expect_u32_in(1..=solution::MAX_TEST_CASES)?;
+    validator.expect_eoln()?;
+    for _ in 0..test_case_count {
+        let classroom_count = validator.expect_u32_in(1..=500_000)?;
+        validator.expect_eoln()?;
+
+        let position = validator.position();
+        let classrooms = validator.expect_token()?;
+        if classrooms.len() != classroom_count as usize
+            || !classrooms.iter().all(|&byte| matches!(byte, b'0' | b'1'))
+        {
+            return Err(read_u32::ValidationError::invalid(
+                format!(
+                    "the classrooms must be exactly {classroom_count} characters of '0' or '1'"
+                ),
+                position,
+            ));
+        }
+        if !classrooms.contains(&b'1') {
+            return Err(read_u32::ValidationError::invalid(
+                "there must be at least one collection point",
+                position,
+            ));
+        }
+        validator.expect_eoln()?;
+    }
+    validator.expect_eof()
+}
+
 #[derive(Debug)]
-struct TestCaseReader<R> {
-    inner: R,
+struct TestCaseReader<R: BufRead> {
+    reader: read_u32::U32Reader<R>,
     counter: u32,
 }
 
 impl<R: BufRead> TestCaseReader<R> {
     fn next_test_case(&mut self) -> Option<impl Iterator<Item = bool> + '_> {
-        /// An iterator over the input test cases.
-        #[derive(Debug)]
-        struct ClassroomReader<R: Read> {
-            inner: R,
-        }
-
-        impl<R: Read> Iterator for ClassroomReader<R> {
-            type Item = bool;
-
-            fn next(&mut self) -> Option<Self::Item> {
-                let mut byte = 0;
-                loop {
-                    return match self.inner.read(std::slice::from_mut(&mut byte)) {
-                        Ok(0) => None,
-                        Ok(..) => match byte {
-                            // '0' means there's no collection point at this classroom.
-                            b'0' => Some(false),
-                            // '1' means there's a collection point at this classroom.
-                            b'1' => Some(true),
-                            // We have reached the end of this test case.
-                            b'\n' => None,
-                            // This should be a part of a \r\n newline, so retry to get the \n.
-                            b'\r' => continue,
-                            // Panic if the input is invalid
-                            // (or probably if the iterator is called again after yielding None).
-                            _ => panic!("Invalid classroom data"),
-                        },
-                        Err(ref e) if e.kind() == std::io::ErrorKind::Interrupted => continue,
-                        // Panic if any unexpected input error occured.
-                        Err(e) => Err(e).expect("An input error occured"),
-                    };
-                }
-            }
-        }
-
         // Return `None` if there are no more test cases.
         self.counter = self.counter.checked_sub(1)?;
 
         // First row is the number of classrooms, which we do not currently use.
-        let _classroom_count = read_u32::U32Reader::new(&mut self.inner).read_until_newline();
+        let _classroom_count: u32 = self.reader.read_until_newline();
 
-        Some(ClassroomReader {
-            inner: &mut self.inner,
-        })
+        // The next row is a string of '0's and '1's.
+        Some(self.reader.read_token().iter().map(|&byte| match byte {
+            // '0' means there's no collection point at this classroom.
+            b'0' => false,
+            // '1' means there's a collection point at this classroom.
+            b'1' => true,
+            // Panic if the input is invalid.
+            _ => panic!("Invalid classroom data"),
+        }))
     }
 }
 
 // Input routine.
-fn input_classrooms(mut input: impl BufRead) -> TestCaseReader<impl BufRead> {
-    let test_case_count = read_u32::U32Reader::new(&mut input).read_until_newline();
+fn input_classrooms(input: impl BufRead) -> TestCaseReader<impl BufRead> {
+    let mut reader = read_u32::U32Reader::with_mode(input, read_u32::Mode::from_env());
+    let test_case_count = reader.read_until_newline();
     TestCaseReader {
-        inner: input,
+        reader,
         counter: test_case_count,
     }
 }
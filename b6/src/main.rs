@@ -1 +1 @@
-kickstart_macros::fn_main!(b6);
+kickstart_macros::fn_main!(b6, slurp);
@@ -1,18 +1,10 @@
 kickstart_macros::fn_run!(b6);
 
+kickstart_macros::sample_tests!(b6, "tests/samples");
+
 // More tests on the logic in `min_distance.rs`.
 // These are mainly I/O tests, to test our custom input iterator.
 
-#[test]
-fn sample() {
-    run(
-        "2\n\
-        3\n111\n\
-        5\n10010",
-        "0\n3\n",
-    );
-}
-
 #[test]
 fn newlines() {
     run(
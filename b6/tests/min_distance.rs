@@ -144,6 +144,6 @@ fn sided() {
 fn big() {
     // A '1' followed by 499_999 '0's gives 1 + 2 + 3 + 4 + ... + 499_999.
     // This number overflows a 32-bit integer, likely the reason why an "answer may be large" hint was given.
-    let iter = std::iter::once(true).chain(std::iter::repeat(false).take(499_999));
+    let iter = std::iter::once(true).chain(std::iter::repeat_n(false, 499_999));
     assert_eq!(minimum_distance(iter), 124_999_750_000);
 }
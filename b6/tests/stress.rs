@@ -0,0 +1,38 @@
+use quickcheck::{Arbitrary, Gen};
+use solution::Inputs;
+
+/// A few test cases with a short row of classrooms,
+/// each with at least one collection point.
+struct TestCases;
+
+impl Inputs for TestCases {
+    type Value = Vec<Vec<bool>>;
+
+    fn generate(g: &mut Gen) -> Self::Value {
+        let len = usize::arbitrary(g) % 3 + 1;
+        (0..len)
+            .map(|_| {
+                let classroom_count = usize::arbitrary(g) % 15 + 1;
+                let mut classrooms: Vec<bool> =
+                    (0..classroom_count).map(|_| bool::arbitrary(g)).collect();
+                // Put a collection point in a random classroom if there is none.
+                if !classrooms.contains(&true) {
+                    classrooms[usize::arbitrary(g) % classroom_count] = true;
+                }
+                classrooms
+            })
+            .collect()
+    }
+
+    fn is_valid(test_cases: &Self::Value) -> bool {
+        !test_cases.is_empty()
+            && test_cases
+                .iter()
+                .all(|classrooms| classrooms.contains(&true))
+    }
+}
+
+#[test]
+fn stress() {
+    solution::stress::<b6::B6, TestCases>(1000);
+}
@@ -0,0 +1,14 @@
+#[test]
+fn valid() {
+    b6::validate(&b"2\n3\n101\n1\n1\n"[..]).unwrap();
+}
+
+#[test]
+fn invalid_classrooms() {
+    // wrong number of classrooms
+    assert!(b6::validate(&b"1\n3\n10\n"[..]).is_err());
+    // not a '0' or '1'
+    assert!(b6::validate(&b"1\n3\n102\n"[..]).is_err());
+    // no collection points
+    assert!(b6::validate(&b"1\n3\n000\n"[..]).is_err());
+}
@@ -0,0 +1,396 @@
+//! Bundles a solution package, with the workspace crates it uses, into a single source file
+//! that can be submitted to an online judge.
+//!
+//! Each workspace library that the solution uses becomes a module of the bundle,
+//! and paths into it are rewritten to go through `crate::`.
+//! Tests and items for disabled features are left out, and the macros that only work within
+//! the workspace (`fn_main!`, `env!("CARGO_PKG_...")` and `#[derive(KickstartInput)]`)
+//! are expanded. Comments are not kept,
+//! except for the doc comments of the solution package.
+//!
+//! Crates from outside the workspace can't be bundled;
+//! a bundle that needs them only works on a judge that provides them.
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::Write as _,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{bail, Context};
+use syn::{parse_quote, punctuated::Punctuated, Ident, Item, Token};
+
+#[path = "../../kickstart_derive/src/input.rs"]
+mod kickstart_input;
+mod load;
+mod rewrite;
+
+use rewrite::{Crates, Rewriter};
+
+/// A crate of the workspace.
+#[derive(Debug)]
+struct Crate {
+    /// The name of the crate in paths, such as `read_u32`.
+    name: String,
+    dir: PathBuf,
+    manifest: toml::Table,
+}
+
+impl Crate {
+    fn table(&self, key: &str) -> Option<&toml::Table> {
+        self.manifest.get(key).and_then(toml::Value::as_table)
+    }
+
+    /// Returns a string in the `[package]` table.
+    fn package(&self, key: &str) -> Option<&str> {
+        self.table("package")?.get(key)?.as_str()
+    }
+
+    fn is_proc_macro(&self) -> bool {
+        self.table("lib")
+            .and_then(|lib| lib.get("proc-macro")?.as_bool())
+            .unwrap_or(false)
+    }
+
+    /// Returns the features of the crate that are enabled when `requested` are,
+    /// along with the default features.
+    fn enabled_features(&self, requested: &BTreeSet<String>) -> BTreeSet<String> {
+        let features = self.table("features");
+        let mut enabled = BTreeSet::new();
+        let mut stack: Vec<&str> = requested.iter().map(String::as_str).collect();
+        stack.push("default");
+
+        while let Some(feature) = stack.pop() {
+            if !enabled.insert(feature.to_owned()) {
+                continue;
+            }
+            let implied = features
+                .and_then(|features| features.get(feature)?.as_array())
+                .into_iter()
+                .flatten()
+                .filter_map(toml::Value::as_str);
+            stack.extend(implied);
+        }
+        enabled
+    }
+
+    /// Returns the dependencies of the crate that are enabled with `features`,
+    /// as the crate names and the tables (or version strings) that specify them.
+    fn dependencies<'a>(
+        &'a self,
+        features: &'a BTreeSet<String>,
+    ) -> impl Iterator<Item = (String, &'a toml::Value)> + 'a {
+        self.table("dependencies")
+            .into_iter()
+            .flatten()
+            .filter(move |(name, spec)| {
+                let optional = spec.get("optional").and_then(toml::Value::as_bool) == Some(true);
+                !optional || features.contains(*name) || features.contains(&format!("dep:{name}"))
+            })
+            .map(|(name, spec)| (name.replace('-', "_"), spec))
+    }
+}
+
+/// The crates of a workspace.
+#[derive(Debug)]
+pub struct Workspace {
+    crates: BTreeMap<String, Crate>,
+}
+
+impl Workspace {
+    /// Reads the manifests of the workspace whose root manifest is in `root`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a manifest cannot be read or parsed.
+    pub fn load(root: &Path) -> anyhow::Result<Self> {
+        let read = |dir: &Path| -> anyhow::Result<toml::Table> {
+            let path = dir.join("Cargo.toml");
+            let source = fs::read_to_string(&path)
+                .with_context(|| format!("Cannot read {}", path.display()))?;
+            source
+                .parse()
+                .with_context(|| format!("Cannot parse {}", path.display()))
+        };
+
+        let root_manifest = read(root)?;
+        let members = root_manifest
+            .get("workspace")
+            .and_then(|workspace| workspace.get("members")?.as_array())
+            .context("The root manifest has no workspace members")?;
+
+        let mut crates = BTreeMap::new();
+        for member in members.iter().filter_map(toml::Value::as_str) {
+            let dir = root.join(member);
+            let mut krate = Crate {
+                name: String::new(),
+                manifest: read(&dir)?,
+                dir,
+            };
+            krate.name = krate
+                .package("name")
+                .with_context(|| format!("The package in `{member}` has no name"))?
+                .replace('-', "_");
+            crates.insert(krate.name.clone(), krate);
+        }
+
+        Ok(Self { crates })
+    }
+
+    /// Returns the features enabled for each workspace crate that `root` depends on,
+    /// including `root` itself.
+    fn features(&self, root: &Crate) -> BTreeMap<String, BTreeSet<String>> {
+        let mut requested: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
+        let mut stack = vec![(root, BTreeSet::new())];
+
+        while let Some((krate, features)) = stack.pop() {
+            let seen = requested.get(&krate.name);
+            if seen.is_some_and(|seen| features.is_subset(seen)) {
+                continue;
+            }
+            let requested = requested.entry(krate.name.clone()).or_default();
+            requested.extend(features);
+
+            let enabled = krate.enabled_features(requested);
+            for (name, spec) in krate.dependencies(&enabled) {
+                let Some(dependency) = self.crates.get(&name) else {
+                    continue;
+                };
+                let features = spec
+                    .get("features")
+                    .and_then(toml::Value::as_array)
+                    .into_iter()
+                    .flatten()
+                    .filter_map(toml::Value::as_str)
+                    .map(str::to_owned)
+                    .collect();
+                stack.push((dependency, features));
+            }
+        }
+
+        requested
+            .into_iter()
+            .map(|(name, requested)| {
+                let enabled = self.crates[&name].enabled_features(&requested);
+                (name, enabled)
+            })
+            .collect()
+    }
+}
+
+/// A solution in a single source file.
+#[derive(Debug)]
+pub struct Bundle {
+    /// The source code.
+    pub source: String,
+    /// The crates from outside the workspace that the bundle needs,
+    /// each with the workspace crates that use it.
+    pub external: BTreeMap<String, BTreeSet<String>>,
+}
+
+/// Bundles the package with the given name, ignoring case.
+///
+/// # Errors
+///
+/// Returns an error if there is no such package,
+/// if the source code of a crate cannot be read or parsed,
+/// or if some code cannot be bundled, such as a procedural macro other than `KickstartInput`.
+pub fn bundle(workspace: &Workspace, package: &str) -> anyhow::Result<Bundle> {
+    let root = workspace
+        .crates
+        .values()
+        .find(|krate| krate.name.eq_ignore_ascii_case(package) && !krate.is_proc_macro())
+        .with_context(|| format!("Unknown package `{package}`"))?;
+    let features = workspace.features(root);
+
+    let (libraries, proc_macros): (BTreeSet<_>, BTreeSet<_>) = workspace
+        .crates
+        .values()
+        .map(|krate| (krate.name.clone(), krate.is_proc_macro()))
+        .partition(|(_, is_proc_macro)| !is_proc_macro);
+    let libraries = libraries.into_iter().map(|(name, _)| name).collect();
+    let proc_macros = proc_macros.into_iter().map(|(name, _)| name).collect();
+
+    let crates = Crates {
+        libraries: &libraries,
+        proc_macros: &proc_macros,
+    };
+    let mut errors = Vec::new();
+
+    // The binary becomes the root of the bundle.
+    let src = root.dir.join("src");
+    let (_, mut main_items) = load::load_file(&src.join("main.rs"), &src, &features[&root.name])?;
+    expand_fn_main(&mut main_items)?;
+    let mut queue: Vec<String> = rewrite(crates, None, root, &mut main_items, &mut errors)
+        .into_iter()
+        .collect();
+
+    let mut modules = Vec::new();
+    let mut bundled = Vec::new();
+    let mut external: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
+    let mut i = 0;
+    while let Some(name) = queue.get(i).cloned() {
+        i += 1;
+        if bundled.contains(&name) {
+            continue;
+        }
+        let krate = &workspace.crates[&name];
+        let features = features.get(&name).cloned().unwrap_or_default();
+
+        let src = krate.dir.join("src");
+        let (attrs, mut items) = load::load_file(&src.join("lib.rs"), &src, &features)?;
+        queue.extend(rewrite(crates, Some(&name), krate, &mut items, &mut errors));
+        // Only the documentation of the solution itself is kept, as judges limit the size of code.
+        if name != root.name {
+            load::strip_docs(&mut items);
+        }
+
+        for (dependency, _) in krate.dependencies(&features) {
+            if !workspace.crates.contains_key(&dependency) {
+                external.entry(dependency).or_default().insert(name.clone());
+            }
+        }
+
+        let ident = Ident::new(&name, proc_macro2::Span::call_site());
+        let attrs = attrs
+            .iter()
+            .filter(|attr| name == root.name || !attr.path().is_ident("doc"));
+        modules.push(Item::Mod(parse_quote! {
+            #(#attrs)*
+            mod #ident {
+                #(#items)*
+            }
+        }));
+        bundled.push(name);
+    }
+
+    if !errors.is_empty() {
+        bail!("Cannot bundle `{}`:\n{}", root.name, errors.join("\n"));
+    }
+
+    let file = syn::File {
+        shebang: None,
+        attrs: vec![parse_quote! {
+            // Most of the bundled libraries is not used by this one program.
+            #![allow(dead_code, unused_imports, unused_macros)]
+        }],
+        items: main_items.into_iter().chain(modules).collect(),
+    };
+
+    Ok(Bundle {
+        source: header(root, &bundled, &external) + &prettyplease::unparse(&file),
+        external,
+    })
+}
+
+/// Rewrites the items of a crate to go into `module` of the bundle,
+/// returning the workspace libraries that they use.
+fn rewrite(
+    crates: Crates<'_>,
+    module: Option<&str>,
+    krate: &Crate,
+    items: &mut Vec<Item>,
+    errors: &mut Vec<String>,
+) -> BTreeSet<String> {
+    let empty = toml::Table::new();
+    let mut rewriter = Rewriter {
+        module,
+        crates,
+        package: krate.table("package").unwrap_or(&empty),
+        used: BTreeSet::new(),
+        errors: Vec::new(),
+    };
+    rewriter.rewrite_items(items);
+    errors.append(&mut rewriter.errors);
+    rewriter.used
+}
+
+/// Replaces `fn_main!` with the `main` function it generates,
+/// as `kickstart_macros` itself is not bundled.
+fn expand_fn_main(items: &mut [Item]) -> anyhow::Result<()> {
+    for item in items {
+        let Item::Macro(mac) = item else { continue };
+        if mac
+            .mac
+            .path
+            .segments
+            .last()
+            .is_none_or(|last| last.ident != "fn_main")
+        {
+            continue;
+        }
+
+        let args = mac
+            .mac
+            .parse_body_with(Punctuated::<Ident, Token![,]>::parse_terminated)
+            .context("Cannot parse the arguments of `fn_main!`")?;
+        // Keep this in sync with `kickstart_macros::fn_main!`.
+        *item = match args.iter().collect::<Vec<_>>()[..] {
+            [package] => parse_quote! {
+                /// Entry point.
+                fn main() {
+                    #package::main_impl(
+                        std::io::stdin().lock(),
+                        write_int::IntWriter::new(std::io::stdout().lock()),
+                    );
+                }
+            },
+            [package, slurp] if slurp == "slurp" => parse_quote! {
+                /// Entry point.
+                fn main() {
+                    let input =
+                        read_u32::slurp(std::io::stdin().lock()).expect("An input error occured");
+                    #package::main_impl(
+                        input.as_slice(),
+                        write_int::IntWriter::new(std::io::stdout().lock()),
+                    );
+                }
+            },
+            _ => bail!("Unexpected arguments to `fn_main!`: {}", mac.mac.tokens),
+        };
+    }
+    Ok(())
+}
+
+/// Returns the comment at the top of the bundle,
+/// which gives the description and the license of the package.
+fn header(
+    root: &Crate,
+    bundled: &[String],
+    external: &BTreeMap<String, BTreeSet<String>>,
+) -> String {
+    let mut header = String::new();
+    if let Some(description) = root.package("description") {
+        let _ = writeln!(header, "// {description}\n//");
+    }
+
+    let authors = root
+        .table("package")
+        .and_then(|package| package.get("authors")?.as_array())
+        .map(|authors| {
+            authors
+                .iter()
+                .filter_map(toml::Value::as_str)
+                .collect::<Vec<_>>()
+                .join(", ")
+        });
+    if let Some(authors) = authors {
+        let _ = writeln!(header, "// Copyright (c) {authors}");
+    }
+    if let Some(license) = root.package("license") {
+        let _ = writeln!(header, "// Licensed under {license}");
+    }
+
+    let _ = writeln!(
+        header,
+        "//\n// Generated by the `bundle` tool from the crates {}; compile it as Rust 2021.",
+        bundled.join(", ")
+    );
+    if !external.is_empty() {
+        let crates: Vec<_> = external.keys().map(String::as_str).collect();
+        let _ = writeln!(header, "// It needs these crates: {}", crates.join(", "));
+    }
+    header.push('\n');
+    header
+}
@@ -0,0 +1,257 @@
+//! Reads a crate into a single tree of items, leaving out tests and disabled items.
+
+use std::{collections::BTreeSet, fs, path::Path};
+
+use anyhow::Context;
+use syn::{
+    punctuated::Punctuated,
+    visit_mut::{self, VisitMut},
+    Attribute, Block, Expr, ExprLit, ImplItem, Item, ItemImpl, ItemTrait, Lit, Meta, Stmt, Token,
+    TraitItem,
+};
+
+/// Reads the module in `file`, whose submodules are in `child_dir`, with all submodules inlined.
+///
+/// Returns the inner attributes and the items of the module.
+pub fn load_file(
+    file: &Path,
+    child_dir: &Path,
+    features: &BTreeSet<String>,
+) -> anyhow::Result<(Vec<Attribute>, Vec<Item>)> {
+    let source =
+        fs::read_to_string(file).with_context(|| format!("Cannot read {}", file.display()))?;
+    let syn::File {
+        attrs, mut items, ..
+    } = syn::parse_file(&source).with_context(|| format!("Cannot parse {}", file.display()))?;
+
+    let file_dir = file.parent().unwrap_or_else(|| Path::new("."));
+    load_items(&mut items, file_dir, child_dir, features)?;
+
+    // Items within items, such as methods and functions in function bodies.
+    let mut strip = Strip { features };
+    for item in &mut items {
+        strip.visit_item_mut(item);
+    }
+
+    Ok((attrs, items))
+}
+
+/// Leaves out the disabled items, and inlines the modules in other files.
+fn load_items(
+    items: &mut Vec<Item>,
+    file_dir: &Path,
+    child_dir: &Path,
+    features: &BTreeSet<String>,
+) -> anyhow::Result<()> {
+    items.retain_mut(|item| attrs_mut(item).is_none_or(|attrs| keep(attrs, features)));
+
+    for item in items {
+        let Item::Mod(module) = item else { continue };
+        let name = module.ident.to_string();
+
+        if let Some((_, content)) = &mut module.content {
+            load_items(content, file_dir, &child_dir.join(&name), features)?;
+            continue;
+        }
+
+        let (file, dir) = take_path_attr(&mut module.attrs).map_or_else(
+            || {
+                let dir = child_dir.join(&name);
+                let file = child_dir.join(format!("{name}.rs"));
+                if file.is_file() {
+                    (file, dir)
+                } else {
+                    (dir.join("mod.rs"), dir)
+                }
+            },
+            |path| {
+                let file = file_dir.join(path);
+                let dir = file.parent().unwrap_or(file_dir).to_owned();
+                (file, dir)
+            },
+        );
+        let (inner, content) = load_file(&file, &dir, features)?;
+        module.attrs.extend(inner);
+        module.content = Some((syn::token::Brace::default(), content));
+        module.semi = None;
+    }
+
+    Ok(())
+}
+
+/// Leaves out the disabled items within other items.
+struct Strip<'a> {
+    features: &'a BTreeSet<String>,
+}
+
+impl VisitMut for Strip<'_> {
+    fn visit_item_impl_mut(&mut self, item: &mut ItemImpl) {
+        item.items.retain_mut(|item| {
+            impl_item_attrs_mut(item).is_none_or(|attrs| keep(attrs, self.features))
+        });
+        visit_mut::visit_item_impl_mut(self, item);
+    }
+
+    fn visit_item_trait_mut(&mut self, item: &mut ItemTrait) {
+        item.items.retain_mut(|item| {
+            trait_item_attrs_mut(item).is_none_or(|attrs| keep(attrs, self.features))
+        });
+        visit_mut::visit_item_trait_mut(self, item);
+    }
+
+    fn visit_block_mut(&mut self, block: &mut Block) {
+        block.stmts.retain_mut(|stmt| match stmt {
+            Stmt::Item(item) => attrs_mut(item).is_none_or(|attrs| keep(attrs, self.features)),
+            _ => true,
+        });
+        visit_mut::visit_block_mut(self, block);
+    }
+}
+
+/// Removes the doc comments within the items.
+pub fn strip_docs(items: &mut [Item]) {
+    for item in items {
+        StripDocs.visit_item_mut(item);
+    }
+}
+
+struct StripDocs;
+
+impl VisitMut for StripDocs {
+    fn visit_attributes_mut(&mut self, attrs: &mut Vec<Attribute>) {
+        attrs.retain(|attr| !attr.path().is_ident("doc"));
+    }
+}
+
+/// Returns whether an item with these attributes is enabled,
+/// and removes the `cfg` attributes that are known to hold.
+///
+/// Tests are never enabled, and neither are items for features that are not enabled.
+/// A `cfg` that depends on the target is kept as is.
+fn keep(attrs: &mut Vec<Attribute>, features: &BTreeSet<String>) -> bool {
+    if attrs.iter().any(|attr| attr.path().is_ident("test")) {
+        return false;
+    }
+
+    let mut enabled = true;
+    attrs.retain(|attr| {
+        if !attr.path().is_ident("cfg") {
+            return true;
+        }
+        match attr
+            .parse_args()
+            .ok()
+            .and_then(|predicate| eval(&predicate, features))
+        {
+            Some(true) => false,
+            Some(false) => {
+                enabled = false;
+                true
+            }
+            None => true,
+        }
+    });
+    enabled
+}
+
+/// Evaluates a `cfg` predicate, or returns `None` if it depends on the target.
+fn eval(predicate: &Meta, features: &BTreeSet<String>) -> Option<bool> {
+    match predicate {
+        Meta::Path(path) if path.is_ident("test") || path.is_ident("doc") => Some(false),
+        Meta::NameValue(option) if option.path.is_ident("feature") => match &option.value {
+            Expr::Lit(ExprLit {
+                lit: Lit::Str(feature),
+                ..
+            }) => Some(features.contains(&feature.value())),
+            _ => None,
+        },
+        Meta::List(list) => {
+            let values: Vec<_> = list
+                .parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)
+                .ok()?
+                .iter()
+                .map(|predicate| eval(predicate, features))
+                .collect();
+            let known = values.iter().all(Option::is_some);
+            if list.path.is_ident("not") {
+                match values[..] {
+                    [value] => value.map(|value| !value),
+                    _ => None,
+                }
+            } else if list.path.is_ident("all") {
+                if values.contains(&Some(false)) {
+                    Some(false)
+                } else {
+                    known.then_some(true)
+                }
+            } else if list.path.is_ident("any") {
+                if values.contains(&Some(true)) {
+                    Some(true)
+                } else {
+                    known.then_some(false)
+                }
+            } else {
+                None
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Removes the `#[path = "..."]` attribute of a module, returning the path.
+fn take_path_attr(attrs: &mut Vec<Attribute>) -> Option<String> {
+    let index = attrs.iter().position(|attr| attr.path().is_ident("path"))?;
+    match &attrs.remove(index).meta {
+        Meta::NameValue(syn::MetaNameValue {
+            value:
+                Expr::Lit(ExprLit {
+                    lit: Lit::Str(path),
+                    ..
+                }),
+            ..
+        }) => Some(path.value()),
+        _ => None,
+    }
+}
+
+/// Returns the attributes of an item, unless it is a verbatim token stream.
+pub const fn attrs_mut(item: &mut Item) -> Option<&mut Vec<Attribute>> {
+    Some(match item {
+        Item::Const(item) => &mut item.attrs,
+        Item::Enum(item) => &mut item.attrs,
+        Item::ExternCrate(item) => &mut item.attrs,
+        Item::Fn(item) => &mut item.attrs,
+        Item::ForeignMod(item) => &mut item.attrs,
+        Item::Impl(item) => &mut item.attrs,
+        Item::Macro(item) => &mut item.attrs,
+        Item::Mod(item) => &mut item.attrs,
+        Item::Static(item) => &mut item.attrs,
+        Item::Struct(item) => &mut item.attrs,
+        Item::Trait(item) => &mut item.attrs,
+        Item::TraitAlias(item) => &mut item.attrs,
+        Item::Type(item) => &mut item.attrs,
+        Item::Union(item) => &mut item.attrs,
+        Item::Use(item) => &mut item.attrs,
+        _ => return None,
+    })
+}
+
+const fn impl_item_attrs_mut(item: &mut ImplItem) -> Option<&mut Vec<Attribute>> {
+    Some(match item {
+        ImplItem::Const(item) => &mut item.attrs,
+        ImplItem::Fn(item) => &mut item.attrs,
+        ImplItem::Type(item) => &mut item.attrs,
+        ImplItem::Macro(item) => &mut item.attrs,
+        _ => return None,
+    })
+}
+
+const fn trait_item_attrs_mut(item: &mut TraitItem) -> Option<&mut Vec<Attribute>> {
+    Some(match item {
+        TraitItem::Const(item) => &mut item.attrs,
+        TraitItem::Fn(item) => &mut item.attrs,
+        TraitItem::Type(item) => &mut item.attrs,
+        TraitItem::Macro(item) => &mut item.attrs,
+        _ => return None,
+    })
+}
@@ -0,0 +1,71 @@
+use std::{fs, io::Write, path::PathBuf};
+
+use anyhow::{bail, Context};
+
+#[derive(clap::Parser, Debug)]
+#[clap(author, version, about)]
+/// Single-file submission bundler for SPCC Kickstart solutions.
+///
+/// The workspace crates that the solution uses are inlined as modules,
+/// `fn_main!` is expanded, and tests are left out.
+struct Args {
+    /// The package to bundle, such as `a5`
+    package: String,
+
+    /// Bundle the solution even if it needs crates from outside the workspace,
+    /// which only some judges provide
+    #[clap(long)]
+    allow_external: bool,
+
+    /// The root of the workspace; the parent of this package if not given
+    #[clap(long)]
+    workspace: Option<PathBuf>,
+
+    /// Write the bundle to this file instead of standard output
+    #[clap(long, short)]
+    output: Option<PathBuf>,
+}
+
+fn main() -> anyhow::Result<()> {
+    let args = <Args as clap::Parser>::parse();
+
+    let root = args.workspace.unwrap_or_else(|| {
+        let manifest_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        manifest_dir.parent().unwrap_or(&manifest_dir).to_owned()
+    });
+    let workspace = bundle::Workspace::load(&root)?;
+    let bundle = bundle::bundle(&workspace, &args.package)?;
+
+    let external: Vec<_> = bundle
+        .external
+        .iter()
+        .map(|(krate, users)| {
+            let users: Vec<_> = users.iter().map(String::as_str).collect();
+            format!("`{krate}` (used by {})", users.join(", "))
+        })
+        .collect();
+    if !external.is_empty() {
+        if !args.allow_external {
+            bail!(
+                "`{}` needs crates from outside the workspace, which cannot be bundled: {}\n\
+                 Pass --allow-external if the judge provides them.",
+                args.package,
+                external.join(", ")
+            );
+        }
+        eprintln!(
+            "warning: the bundle needs crates from outside the workspace: {}",
+            external.join(", ")
+        );
+    }
+
+    match &args.output {
+        Some(path) => fs::write(path, &bundle.source)
+            .with_context(|| format!("Cannot write {}", path.display()))?,
+        None => std::io::stdout()
+            .lock()
+            .write_all(bundle.source.as_bytes())?,
+    }
+
+    Ok(())
+}
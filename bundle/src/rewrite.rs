@@ -0,0 +1,327 @@
+//! Rewrites the items of a crate to work as a module of the bundle.
+
+use std::collections::BTreeSet;
+
+use proc_macro2::{Group, Ident, Punct, Spacing, TokenStream, TokenTree};
+use syn::{
+    parse_quote,
+    punctuated::Punctuated,
+    visit_mut::{self, VisitMut},
+    DeriveInput, Expr, ExprMacro, ExprPath, Item, ItemMod, ItemUse, LitStr, Macro, Path,
+    PathSegment, Token, TypePath, UseName, UsePath, UseTree,
+};
+
+use crate::kickstart_input;
+
+/// The workspace crates, by how they can be bundled.
+#[derive(Clone, Copy, Debug)]
+pub struct Crates<'a> {
+    /// The library crates, which can be inlined as modules.
+    pub libraries: &'a BTreeSet<String>,
+    /// The procedural macro crates, whose macros must be expanded instead.
+    pub proc_macros: &'a BTreeSet<String>,
+}
+
+/// Rewrites the paths of a crate, so that:
+/// - `crate::` paths point to the module of the crate, instead of the root of the bundle;
+/// - paths to other workspace crates point to their modules.
+///
+/// `env!("CARGO_PKG_...")` and `#[derive(KickstartInput)]` are also expanded,
+/// as the bundle is not compiled as part of the package.
+#[derive(Debug)]
+pub struct Rewriter<'a> {
+    /// The module of the crate in the bundle, or `None` for the root of the bundle.
+    pub module: Option<&'a str>,
+    pub crates: Crates<'a>,
+    /// The `[package]` table of the manifest, which `env!` reads from.
+    pub package: &'a toml::Table,
+    /// The workspace libraries that the rewritten items use.
+    pub used: BTreeSet<String>,
+    /// What could not be rewritten.
+    pub errors: Vec<String>,
+}
+
+impl Rewriter<'_> {
+    /// Rewrites the items of a module.
+    pub fn rewrite_items(&mut self, items: &mut Vec<Item>) {
+        // A proc macro is expanded where it is used, so importing it is not needed.
+        items.retain(|item| match item {
+            Item::Use(item) => !self.is_proc_macro(root_of(&item.tree)),
+            Item::ExternCrate(item) => !self.is_proc_macro(Some(&item.ident)),
+            _ => true,
+        });
+
+        let mut i = 0;
+        while i < items.len() {
+            if let Item::Struct(item) = &mut items[i] {
+                if take_derive(&mut item.attrs, "KickstartInput") {
+                    let input = DeriveInput::from(item.clone());
+                    // The helper attributes of the derive are not attributes of their own.
+                    for field in &mut item.fields {
+                        field.attrs.retain(|attr| {
+                            !["sep", "count_from", "count_prefixed", "line"]
+                                .iter()
+                                .any(|helper| attr.path().is_ident(helper))
+                        });
+                    }
+                    match kickstart_input::expand(&input).and_then(syn::parse2) {
+                        Ok(expanded) => items.insert(i + 1, expanded),
+                        Err(error) => self.errors.push(format!(
+                            "cannot derive `KickstartInput` for `{}`: {error}",
+                            input.ident
+                        )),
+                    }
+                }
+            }
+            i += 1;
+        }
+
+        for item in items {
+            self.visit_item_mut(item);
+        }
+    }
+
+    fn is_proc_macro(&self, name: Option<&Ident>) -> bool {
+        name.is_some_and(|name| self.crates.proc_macros.contains(&name.to_string()))
+    }
+
+    /// Returns the module, in the root of the bundle, that a path starting with `first` goes into,
+    /// or `None` if the path stays as it is.
+    ///
+    /// `crate::` paths go into the module of the crate, and paths into a workspace library
+    /// go into the module of that library.
+    fn module_of(&mut self, first: &Ident) -> Option<Ident> {
+        let name = first.to_string();
+        if name == "crate" {
+            self.module.map(|module| Ident::new(module, first.span()))
+        } else if self.crates.libraries.contains(&name) {
+            self.used.insert(name);
+            Some(first.clone())
+        } else {
+            if self.crates.proc_macros.contains(&name) {
+                self.errors.push(format!(
+                    "`{name}` is a procedural macro crate, which cannot be bundled"
+                ));
+            }
+            None
+        }
+    }
+
+    /// Rewrites the paths at the start of a `use` tree.
+    fn rewrite_use_tree(&mut self, tree: &mut UseTree) {
+        let first = match tree {
+            UseTree::Path(UsePath { ident, .. })
+            | UseTree::Name(UseName { ident })
+            | UseTree::Rename(syn::UseRename { ident, .. }) => ident.clone(),
+            UseTree::Group(group) => {
+                group
+                    .items
+                    .iter_mut()
+                    .for_each(|tree| self.rewrite_use_tree(tree));
+                return;
+            }
+            UseTree::Glob(_) => return,
+        };
+        let Some(module) = self.module_of(&first) else {
+            return;
+        };
+
+        // `crate::{a, b}` becomes `crate::module::{a, b}`, and `read_u32` becomes `crate::read_u32`.
+        let mut rest = tree.clone();
+        if let UseTree::Path(UsePath { ident, .. })
+        | UseTree::Name(UseName { ident })
+        | UseTree::Rename(syn::UseRename { ident, .. }) = &mut rest
+        {
+            *ident = module;
+        }
+        *tree = UseTree::Path(UsePath {
+            ident: Ident::new("crate", first.span()),
+            colon2_token: <Token![::]>::default(),
+            tree: Box::new(rest),
+        });
+    }
+
+    /// Rewrites the paths in the tokens of a macro, including `$crate`.
+    fn rewrite_tokens(&mut self, tokens: TokenStream) -> TokenStream {
+        let tokens: Vec<TokenTree> = tokens.into_iter().collect();
+        let mut rewritten = Vec::with_capacity(tokens.len());
+
+        for (i, token) in tokens.iter().enumerate() {
+            match token {
+                TokenTree::Group(group) => {
+                    let mut new =
+                        Group::new(group.delimiter(), self.rewrite_tokens(group.stream()));
+                    new.set_span(group.span());
+                    rewritten.push(new.into());
+                }
+                TokenTree::Ident(ident) if starts_path(&tokens, i) => {
+                    let after_dollar = i > 0 && is_punct(&tokens[i - 1], '$');
+                    // `$name::` is a metavariable, but `$crate::` is a path.
+                    let module = if after_dollar && ident != "crate" {
+                        None
+                    } else {
+                        self.module_of(ident)
+                    };
+                    match module {
+                        Some(module) => {
+                            rewritten.push(Ident::new("crate", ident.span()).into());
+                            rewritten.extend(path_separator());
+                            rewritten.push(module.into());
+                        }
+                        None => rewritten.push(token.clone()),
+                    }
+                }
+                _ => rewritten.push(token.clone()),
+            }
+        }
+
+        rewritten.into_iter().collect()
+    }
+
+    /// Expands `env!("CARGO_PKG_...")` with the manifest of the package.
+    fn expand_env(&mut self, mac: &Macro) -> Option<Expr> {
+        if !mac.path.is_ident("env") {
+            return None;
+        }
+        let name = mac.parse_body::<LitStr>().ok()?.value();
+        let key = name.strip_prefix("CARGO_PKG_")?.to_ascii_lowercase();
+        let value = match self.package.get(&key) {
+            Some(toml::Value::String(value)) => value.clone(),
+            Some(toml::Value::Array(values)) => values
+                .iter()
+                .filter_map(toml::Value::as_str)
+                .collect::<Vec<_>>()
+                .join(":"),
+            _ => {
+                self.errors.push(format!(
+                    "cannot expand `env!({name:?})` without `{key}` in the manifest"
+                ));
+                return None;
+            }
+        };
+        Some(parse_quote!(#value))
+    }
+}
+
+impl VisitMut for Rewriter<'_> {
+    fn visit_item_mod_mut(&mut self, module: &mut ItemMod) {
+        if let Some((_, items)) = &mut module.content {
+            self.rewrite_items(items);
+        }
+        for attr in &mut module.attrs {
+            self.visit_attribute_mut(attr);
+        }
+    }
+
+    fn visit_item_use_mut(&mut self, item: &mut ItemUse) {
+        item.leading_colon = None;
+        self.rewrite_use_tree(&mut item.tree);
+    }
+
+    fn visit_path_mut(&mut self, path: &mut Path) {
+        visit_mut::visit_path_mut(self, path);
+
+        // A single identifier can't be a path into another crate, and `pub(crate)` must stay.
+        if path.segments.len() < 2 {
+            return;
+        }
+        let first = &path.segments[0].ident;
+        if let Some(module) = self.module_of(first) {
+            let krate = Ident::new("crate", first.span());
+            path.segments[0].ident = module;
+            path.segments.insert(0, PathSegment::from(krate));
+            path.leading_colon = None;
+        }
+    }
+
+    fn visit_expr_path_mut(&mut self, expr: &mut ExprPath) {
+        let len = expr.path.segments.len();
+        visit_mut::visit_expr_path_mut(self, expr);
+        // `<T as read_u32::Trait>::f` gains a segment before the end of the trait.
+        if let Some(qself) = &mut expr.qself {
+            qself.position += expr.path.segments.len() - len;
+        }
+    }
+
+    fn visit_type_path_mut(&mut self, ty: &mut TypePath) {
+        let len = ty.path.segments.len();
+        visit_mut::visit_type_path_mut(self, ty);
+        if let Some(qself) = &mut ty.qself {
+            qself.position += ty.path.segments.len() - len;
+        }
+    }
+
+    fn visit_macro_mut(&mut self, mac: &mut Macro) {
+        self.visit_path_mut(&mut mac.path);
+        mac.tokens = self.rewrite_tokens(std::mem::take(&mut mac.tokens));
+    }
+
+    fn visit_expr_mut(&mut self, expr: &mut Expr) {
+        if let Expr::Macro(ExprMacro { mac, .. }) = expr {
+            if let Some(expanded) = self.expand_env(mac) {
+                *expr = expanded;
+                return;
+            }
+        }
+        visit_mut::visit_expr_mut(self, expr);
+    }
+}
+
+/// Returns the first identifier of a `use` tree, unless it starts with a group or a glob.
+const fn root_of(tree: &UseTree) -> Option<&Ident> {
+    match tree {
+        UseTree::Path(UsePath { ident, .. })
+        | UseTree::Name(UseName { ident })
+        | UseTree::Rename(syn::UseRename { ident, .. }) => Some(ident),
+        UseTree::Group(_) | UseTree::Glob(_) => None,
+    }
+}
+
+/// Removes the derive whose path ends with `name` from the attributes,
+/// returning whether it was there.
+fn take_derive(attrs: &mut Vec<syn::Attribute>, name: &str) -> bool {
+    let mut found = false;
+    attrs.retain_mut(|attr| {
+        if !attr.path().is_ident("derive") {
+            return true;
+        }
+        let Ok(derives) = attr.parse_args_with(Punctuated::<Path, Token![,]>::parse_terminated)
+        else {
+            return true;
+        };
+        let (taken, rest): (Vec<_>, Vec<_>) = derives
+            .into_iter()
+            .partition(|path| path.segments.last().is_some_and(|last| last.ident == name));
+        if taken.is_empty() {
+            return true;
+        }
+        found = true;
+        if rest.is_empty() {
+            return false;
+        }
+        *attr = parse_quote!(#[derive(#(#rest),*)]);
+        true
+    });
+    found
+}
+
+/// Returns whether the identifier at `i` starts a path of more than one segment,
+/// rather than continuing one.
+fn starts_path(tokens: &[TokenTree], i: usize) -> bool {
+    let followed = tokens.get(i + 1).is_some_and(|token| is_punct(token, ':'))
+        && tokens.get(i + 2).is_some_and(|token| is_punct(token, ':'));
+    let preceded = i > 0 && (is_punct(&tokens[i - 1], ':') || is_punct(&tokens[i - 1], '.'));
+    followed && !preceded
+}
+
+fn is_punct(token: &TokenTree, c: char) -> bool {
+    matches!(token, TokenTree::Punct(punct) if punct.as_char() == c)
+}
+
+/// The tokens of `::`.
+fn path_separator() -> [TokenTree; 2] {
+    [
+        Punct::new(':', Spacing::Joint).into(),
+        Punct::new(':', Spacing::Alone).into(),
+    ]
+}
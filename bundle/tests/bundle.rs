@@ -0,0 +1,76 @@
+use std::path::Path;
+
+const PACKAGES: [&str; 12] = [
+    "a1", "a2", "a3", "a4", "a5", "a6", "b1", "b2", "b3", "b4", "b5", "b6",
+];
+
+fn workspace() -> bundle::Workspace {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+    bundle::Workspace::load(root).unwrap()
+}
+
+#[test]
+fn every_package() {
+    let workspace = workspace();
+    for package in PACKAGES {
+        let bundle = bundle::bundle(&workspace, package).unwrap();
+        let file = syn::parse_file(&bundle.source)
+            .unwrap_or_else(|error| panic!("{package} does not parse: {error}"));
+
+        assert!(bundle
+            .source
+            .starts_with("// Solution for SPCC Kickstart Problem"));
+        assert!(bundle
+            .source
+            .contains("// Licensed under MIT OR Apache-2.0"));
+        assert!(
+            file.items
+                .iter()
+                .any(|item| matches!(item, syn::Item::Fn(main) if main.sig.ident == "main")),
+            "{package} has no `main`"
+        );
+        for unbundled in [
+            "fn_main!",
+            "kickstart_macros",
+            "#[test]",
+            "cfg(test)",
+            "env!(",
+        ] {
+            assert!(
+                !bundle.source.contains(unbundled),
+                "{package} contains `{unbundled}`"
+            );
+        }
+    }
+}
+
+#[test]
+fn external_crates() {
+    let workspace = workspace();
+
+    let a3 = bundle::bundle(&workspace, "a3").unwrap();
+    assert!(a3.external["unicode_segmentation"].contains("a3"));
+    assert!(a3.external["lexical"].contains("read_u32"));
+    assert!(a3.source.contains("// It needs these crates: "));
+
+    let a1 = bundle::bundle(&workspace, "a1").unwrap();
+    assert!(!a1.external.contains_key("unicode_segmentation"));
+}
+
+#[test]
+fn derive_is_expanded() {
+    let b4 = bundle::bundle(&workspace(), "b4").unwrap();
+    assert!(!b4.source.contains("KickstartInput)]"));
+    assert!(b4
+        .source
+        .contains("impl crate::read_u32::KickstartInput for"));
+}
+
+#[test]
+fn package_names() {
+    let workspace = workspace();
+    assert!(bundle::bundle(&workspace, "A5").is_ok());
+    assert!(bundle::bundle(&workspace, "c1").is_err());
+    // Procedural macros are not solutions.
+    assert!(bundle::bundle(&workspace, "kickstart_derive").is_err());
+}
@@ -0,0 +1,15 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+/// A missing line, or one that is not UTF-8.
+const ACCEPTED: &[&str] = &["No input was provided", "An input error occured"];
+
+fuzz_target!(|input: &[u8]| {
+    kickstart_fuzz::check_main_impl(
+        input,
+        |input| a3::validate(input),
+        |input, output| a3::main_impl(input, output),
+        ACCEPTED,
+    );
+});
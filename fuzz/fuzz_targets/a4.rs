@@ -0,0 +1,15 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+/// Anything the reader cannot read as the number it expects.
+const ACCEPTED: &[&str] = &["Failed to read an input number"];
+
+fuzz_target!(|input: &[u8]| {
+    kickstart_fuzz::check_main_impl(
+        input,
+        |input| a4::validate(input),
+        |input, output| a4::main_impl(input, output),
+        ACCEPTED,
+    );
+});
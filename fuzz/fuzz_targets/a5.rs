@@ -0,0 +1,20 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+/// Anything that does not follow the layout of the input, a price of zero,
+/// and a wallet too large for the formula.
+const ACCEPTED: &[&str] = &[
+    "Failed to read the input",
+    "The price must be positive",
+    "The wallet must have at most",
+];
+
+fuzz_target!(|input: &[u8]| {
+    kickstart_fuzz::check_main_impl(
+        input,
+        |input| a5::validate(input),
+        |input, output| a5::main_impl(input, output),
+        ACCEPTED,
+    );
+});
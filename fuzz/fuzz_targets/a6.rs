@@ -0,0 +1,21 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+/// A count or a row of numbers that the reader cannot read,
+/// counts too large to allocate for, and numbers too small for `solve`.
+const ACCEPTED: &[&str] = &[
+    "Failed to read an input number",
+    "Failed to read a row of input numbers",
+    "Too many numbers",
+    "The numbers must be at least 2",
+];
+
+fuzz_target!(|input: &[u8]| {
+    kickstart_fuzz::check_main_impl(
+        input,
+        |input| a6::validate(input),
+        |input, output| a6::main_impl(input, output),
+        ACCEPTED,
+    );
+});
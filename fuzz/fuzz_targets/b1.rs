@@ -0,0 +1,18 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+/// Anything that does not follow the layout of the input, and a team of no one.
+const ACCEPTED: &[&str] = &[
+    "Failed to read the input",
+    "A team must have at least one member",
+];
+
+fuzz_target!(|input: &[u8]| {
+    kickstart_fuzz::check_main_impl(
+        input,
+        |input| b1::validate(input),
+        |input, output| b1::main_impl(input, output),
+        ACCEPTED,
+    );
+});
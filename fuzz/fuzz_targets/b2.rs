@@ -0,0 +1,15 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+/// Every input is read as a word, which may be empty, so nothing is rejected with a panic.
+const ACCEPTED: &[&str] = &[];
+
+fuzz_target!(|input: &[u8]| {
+    kickstart_fuzz::check_main_impl(
+        input,
+        |input| b2::validate(input),
+        |input, output| b2::main_impl(input, output),
+        ACCEPTED,
+    );
+});
@@ -0,0 +1,15 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+/// Anything that is not three numbers on one line.
+const ACCEPTED: &[&str] = &["Failed to read an input number"];
+
+fuzz_target!(|input: &[u8]| {
+    kickstart_fuzz::check_main_impl(
+        input,
+        |input| b3::validate(input),
+        |input, output| b3::main_impl(input, output),
+        ACCEPTED,
+    );
+});
@@ -0,0 +1,19 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+/// Anything that does not follow the layout of the input,
+/// and too many sticks and diamonds to count emeralds in a `u32`.
+const ACCEPTED: &[&str] = &[
+    "Failed to read the input",
+    "There must be at most 1000000000 sticks and diamonds",
+];
+
+fuzz_target!(|input: &[u8]| {
+    kickstart_fuzz::check_main_impl(
+        input,
+        |input| b4::validate(input),
+        |input, output| b4::main_impl(input, output),
+        ACCEPTED,
+    );
+});
@@ -0,0 +1,15 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+/// None: a malformed board is simply not a valid sudoku, and only I/O errors panic.
+const ACCEPTED: &[&str] = &[];
+
+fuzz_target!(|input: &[u8]| {
+    kickstart_fuzz::check_main_impl(
+        input,
+        |input| b5::validate(input),
+        |input, output| b5::main_impl(input, output),
+        ACCEPTED,
+    );
+});
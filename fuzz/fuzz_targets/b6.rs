@@ -0,0 +1,21 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+/// Anything the reader cannot read, a classroom that is neither `0` nor `1`,
+/// and a row without any collection point.
+const ACCEPTED: &[&str] = &[
+    "Failed to read an input number",
+    "Failed to read an input token",
+    "Invalid classroom data",
+    "There is no collection point",
+];
+
+fuzz_target!(|input: &[u8]| {
+    kickstart_fuzz::check_main_impl(
+        input,
+        |input| b6::validate(input),
+        |input, output| b6::main_impl(input, output),
+        ACCEPTED,
+    );
+});
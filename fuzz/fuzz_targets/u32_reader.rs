@@ -0,0 +1,73 @@
+#![no_main]
+
+use std::io::BufReader;
+
+use arbitrary::Arbitrary;
+use libfuzzer_sys::fuzz_target;
+use read_u32::{Mode, ReadError, U32Reader};
+
+/// A read from a `U32Reader`.
+#[derive(Arbitrary, Debug)]
+enum Op {
+    UntilSpaceU32,
+    UntilNewlineU32,
+    UntilSpaceI64,
+    UntilNewlineF64,
+    Row(u8),
+    Token,
+    LineBytes,
+    FixedRow,
+}
+
+#[derive(Arbitrary, Debug)]
+struct Input {
+    lenient: bool,
+    capacity: u8,
+    ops: Vec<Op>,
+    data: Vec<u8>,
+}
+
+/// Runs `op`, returning its result in a comparable form.
+fn run(reader: &mut U32Reader<impl std::io::BufRead>, op: &Op) -> Result<String, ReadError> {
+    Ok(match *op {
+        Op::UntilSpaceU32 => format!("{:?}", reader.try_read_until_space::<u32>()?),
+        Op::UntilNewlineU32 => format!("{:?}", reader.try_read_until_newline::<u32>()?),
+        Op::UntilSpaceI64 => format!("{:?}", reader.try_read_until_space::<i64>()?),
+        Op::UntilNewlineF64 => format!("{:?}", reader.try_read_until_newline::<f64>()?),
+        Op::Row(n) => format!("{:?}", reader.try_read_row::<u32>(n.into())?),
+        Op::Token => format!("{:?}", reader.try_read_token()?),
+        Op::LineBytes => format!("{:?}", reader.try_read_line_bytes()?),
+        Op::FixedRow => format!("{:?}", reader.try_read_fixed_row::<3>()?),
+    })
+}
+
+// The `try_*` methods must never panic, and a reader that has the whole input in its buffer
+// must read exactly what one that sees it a few bytes at a time does,
+// even though numbers and tokens are split between buffers only in the latter.
+fuzz_target!(|input: Input| {
+    let mode = if input.lenient {
+        Mode::Lenient
+    } else {
+        Mode::Strict
+    };
+    let mut whole = U32Reader::with_mode(&input.data[..], mode);
+    let mut split = U32Reader::with_mode(
+        BufReader::with_capacity(usize::from(input.capacity % 16) + 1, &input.data[..]),
+        mode,
+    );
+
+    for op in &input.ops {
+        let expected = run(&mut whole, op).map_err(|error| error.to_string());
+        let found = run(&mut split, op).map_err(|error| error.to_string());
+        assert_eq!(expected, found, "{op:?} differs after a split");
+        // How much of the input an error consumes is unspecified, so stop at the first one.
+        if expected.is_err() {
+            break;
+        }
+        assert_eq!(
+            whole.position(),
+            split.position(),
+            "{op:?} moved differently"
+        );
+    }
+});
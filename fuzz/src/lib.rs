@@ -0,0 +1,69 @@
+//! Shared checks for the fuzz targets.
+//!
+//! Every `main_impl` panics on malformed input, which is fine as long as the panic comes from
+//! the input routine rejecting the input. Any other panic, such as an index out of bounds or an
+//! arithmetic overflow, is a bug, and so is any panic at all on an input that passes `validate`.
+
+use std::{
+    cell::Cell,
+    panic::{self, AssertUnwindSafe},
+    sync::Once,
+};
+
+thread_local! {
+    /// Whether a panic is currently expected, so the panic hook should stay silent.
+    static REJECTING: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Runs `main_impl` on `input`, and crashes unless it either succeeds,
+/// or rejects invalid input with a panic whose message starts with one of `accepted`.
+///
+/// # Panics
+///
+/// Panics if `main_impl` panics on an input that `validate` accepts,
+/// or with a message that is not accepted.
+pub fn check_main_impl<E>(
+    input: &[u8],
+    validate: impl FnOnce(&[u8]) -> Result<(), E>,
+    main_impl: impl FnOnce(&[u8], &mut Vec<u8>),
+    accepted: &[&str],
+) {
+    install_hook();
+    let mut output = Vec::new();
+
+    // A valid input must always be answered, so let libFuzzer see any panic.
+    if validate(input).is_ok() {
+        main_impl(input, &mut output);
+        return;
+    }
+
+    REJECTING.set(true);
+    let result = panic::catch_unwind(AssertUnwindSafe(|| main_impl(input, &mut output)));
+    REJECTING.set(false);
+
+    if let Err(payload) = result {
+        let message = payload
+            .downcast_ref::<String>()
+            .map(String::as_str)
+            .or_else(|| payload.downcast_ref::<&str>().copied())
+            .unwrap_or_default();
+        assert!(
+            accepted.iter().any(|prefix| message.starts_with(prefix)),
+            "invalid input was not rejected cleanly: {message}"
+        );
+    }
+}
+
+/// Wraps the panic hook of libFuzzer, which aborts the process,
+/// so that it is skipped for panics that may turn out to be accepted.
+fn install_hook() {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        let fuzzer_hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if !REJECTING.get() {
+                fuzzer_hook(info);
+            }
+        }));
+    });
+}
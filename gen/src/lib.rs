@@ -0,0 +1,335 @@
+//! Random input generators for every problem, which follow the constraints of the statements.
+//!
+//! Every generator takes a seeded random number generator,
+//! so the same seed and parameters always give the same input.
+
+use std::{
+    io::{self, prelude::*},
+    ops::RangeInclusive,
+};
+
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+use write_int::WriteInt;
+
+/// What to generate.
+///
+/// Each parameter is clamped to what the statement allows,
+/// so an input is always valid whatever the parameters.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Params {
+    /// The number of test cases, for problems that have them.
+    ///
+    /// By default, this is a small random number, or the largest allowed in a max test.
+    pub test_cases: Option<u32>,
+    /// The number of numbers or characters, depending on the problem.
+    ///
+    /// By default, this is a small random number, or the largest allowed in a max test.
+    pub size: Option<u32>,
+    /// The largest number in the input, for problems where it is bounded.
+    ///
+    /// By default, numbers can be as large as the statement allows.
+    pub max_value: Option<u32>,
+    /// Generate the largest input allowed, unless the size is given.
+    pub max_test: bool,
+}
+
+/// The largest size when neither the size nor a max test is asked for.
+const SMALL: u32 = 10;
+
+impl Params {
+    fn test_cases(&self, rng: &mut StdRng, max: u32) -> u32 {
+        pick(rng, self.test_cases, 1..=max, self.max_test)
+    }
+
+    fn size(&self, rng: &mut StdRng, range: RangeInclusive<u32>) -> u32 {
+        pick(rng, self.size, range, self.max_test)
+    }
+
+    fn value(&self, rng: &mut StdRng, range: RangeInclusive<u32>) -> u32 {
+        let (min, max) = range.into_inner();
+        let max = self.max_value.map_or(max, |value| value.clamp(min, max));
+        rng.gen_range(min..=max)
+    }
+}
+
+/// Returns `given` within `range`, or otherwise a small random number or the maximum.
+fn pick(rng: &mut StdRng, given: Option<u32>, range: RangeInclusive<u32>, max_test: bool) -> u32 {
+    let (min, max) = range.into_inner();
+    given.map_or_else(
+        || {
+            if max_test {
+                max
+            } else {
+                rng.gen_range(min..=max.min(min + SMALL - 1))
+            }
+        },
+        |given| given.clamp(min, max),
+    )
+}
+
+/// A function that writes a random input of a problem.
+pub type Generate = fn(&mut StdRng, &Params, &mut dyn Write) -> io::Result<()>;
+
+/// A generator of the inputs of a problem.
+#[derive(Clone, Copy, Debug)]
+pub struct Generator {
+    /// The lowercase problem number, such as `"a5"`.
+    pub name: &'static str,
+    pub generate: Generate,
+}
+
+macro_rules! generators {
+    ($($problem:ident),* $(,)?) => {
+        /// The generators for all problems, in order.
+        pub const GENERATORS: &[Generator] = &[$(
+            Generator {
+                name: stringify!($problem),
+                generate: $problem,
+            },
+        )*];
+    };
+}
+
+generators![a1, a2, a3, a4, a5, a6, b1, b2, b3, b4, b5, b6];
+
+/// Returns the generator for the problem with the given name, ignoring case.
+#[must_use]
+pub fn find(name: &str) -> Option<&'static Generator> {
+    GENERATORS
+        .iter()
+        .find(|generator| generator.name.eq_ignore_ascii_case(name))
+}
+
+impl Generator {
+    /// Writes the slowest input known for the problem.
+    ///
+    /// This is the max test with seed 0, except for A6,
+    /// where random numbers are easy and the bad case from `a6_benchgen` is used instead.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing to `output` fails.
+    pub fn worst_case(&self, output: &mut dyn Write) -> io::Result<()> {
+        let mut rng = StdRng::seed_from_u64(0);
+        let params = Params {
+            max_test: true,
+            ..Params::default()
+        };
+
+        if self.name == "a6" {
+            a6_bad_case(&mut rng, &params, output)
+        } else {
+            (self.generate)(&mut rng, &params, output)
+        }
+    }
+}
+
+/// Writes `len` random characters from `alphabet`.
+fn write_chars(
+    rng: &mut StdRng,
+    alphabet: &[u8],
+    len: u32,
+    output: &mut dyn Write,
+) -> io::Result<()> {
+    (0..len).try_for_each(|_| output.write_all(&[*alphabet.choose(rng).unwrap()]))
+}
+
+/// A name, which starts with a capital letter.
+fn a1(rng: &mut StdRng, params: &Params, output: &mut dyn Write) -> io::Result<()> {
+    // The statement doesn't bound the length; 100 is plenty for a name.
+    let len = params.size(rng, 1..=100);
+    write_chars(rng, b"ABCDEFGHIJKLMNOPQRSTUVWXYZ", 1, output)?;
+    write_chars(rng, b"abcdefghijklmnopqrstuvwxyz", len - 1, output)?;
+    output.write_newline()
+}
+
+/// A temperature with one decimal place, around the normal range.
+fn a2(rng: &mut StdRng, _: &Params, output: &mut dyn Write) -> io::Result<()> {
+    let tenths = rng.gen_range(340..=400);
+    writeln!(output, "{}.{}", tenths / 10, tenths % 10)
+}
+
+/// A line of words of letters, some of which are vowels.
+fn a3(rng: &mut StdRng, params: &Params, output: &mut dyn Write) -> io::Result<()> {
+    const LETTERS: &[u8] = b"aeiouAEIOUbcdfgxyzBCDFGXYZ";
+    let len = params.size(rng, 1..=100);
+    // The line must neither start nor end with a space.
+    write_chars(rng, LETTERS, 1, output)?;
+    if len > 1 {
+        write_chars(rng, b"aeiouAEIOUbcdfgxyzBCDFGXYZ   ", len - 2, output)?;
+        write_chars(rng, LETTERS, 1, output)?;
+    }
+    output.write_newline()
+}
+
+/// The number of stairs.
+fn a4(rng: &mut StdRng, params: &Params, output: &mut dyn Write) -> io::Result<()> {
+    let stairs = if params.max_test {
+        1_000_000
+    } else {
+        params.value(rng, 1..=1_000_000)
+    };
+    output.write_u32(stairs)?;
+    output.write_newline()
+}
+
+/// Test cases of a price and a wallet.
+fn a5(rng: &mut StdRng, params: &Params, output: &mut dyn Write) -> io::Result<()> {
+    let test_cases = params.test_cases(rng, solution::MAX_TEST_CASES);
+    output.write_u32(test_cases)?;
+    output.write_newline()?;
+    for _ in 0..test_cases {
+        output.write_row([
+            params.value(rng, 1..=1000),
+            params.value(rng, 1..=1_000_000),
+        ])?;
+        output.write_newline()?;
+    }
+    Ok(())
+}
+
+/// A list of numbers.
+fn a6(rng: &mut StdRng, params: &Params, output: &mut dyn Write) -> io::Result<()> {
+    let len = params.size(rng, 1..=1_000_000);
+    output.write_u32(len)?;
+    output.write_newline()?;
+    let numbers: Vec<u32> = (0..len).map(|_| params.value(rng, 2..=1_000_000)).collect();
+    output.write_row(numbers)?;
+    output.write_newline()
+}
+
+/// The bad case from `a6_benchgen`, filled up to the most numbers allowed like with `--fill`.
+fn a6_bad_case(rng: &mut StdRng, _: &Params, output: &mut dyn Write) -> io::Result<()> {
+    const LEN: usize = 1_000_000;
+
+    let mut numbers: Vec<u32> = a6_benchgen::bad_case().collect();
+    numbers.shuffle(rng);
+    numbers.extend_from_within(..LEN - numbers.len());
+    numbers.shuffle(rng);
+
+    writeln!(output, "{LEN}")?;
+    output.write_row(numbers)?;
+    output.write_newline()
+}
+
+/// Test cases of a cost and a team size.
+fn b1(rng: &mut StdRng, params: &Params, output: &mut dyn Write) -> io::Result<()> {
+    let test_cases = params.test_cases(rng, solution::MAX_TEST_CASES);
+    output.write_u32(test_cases)?;
+    output.write_newline()?;
+    for _ in 0..test_cases {
+        output.write_row([
+            params.value(rng, 1..=1_000_000),
+            params.value(rng, 1..=1_000_000),
+        ])?;
+        output.write_newline()?;
+    }
+    Ok(())
+}
+
+/// A word of capital letters.
+fn b2(rng: &mut StdRng, params: &Params, output: &mut dyn Write) -> io::Result<()> {
+    // The statement doesn't bound the length.
+    let len = params.size(rng, 1..=100_000);
+    // Without other letters, the answer is always YES.
+    let alphabet: &[u8] = if rng.gen() {
+        b"HINOSXZ"
+    } else {
+        b"ABCDEFGHIJKLMNOPQRSTUVWXYZ"
+    };
+    write_chars(rng, alphabet, len, output)?;
+    output.write_newline()
+}
+
+/// The positions of three classrooms.
+fn b3(rng: &mut StdRng, params: &Params, output: &mut dyn Write) -> io::Result<()> {
+    let positions = [(); 3].map(|()| params.value(rng, 0..=u32::MAX));
+    output.write_row(positions)?;
+    output.write_newline()
+}
+
+/// Test cases of sticks and diamonds.
+fn b4(rng: &mut StdRng, params: &Params, output: &mut dyn Write) -> io::Result<()> {
+    let test_cases = params.test_cases(rng, solution::MAX_TEST_CASES);
+    output.write_u32(test_cases)?;
+    output.write_newline()?;
+    for _ in 0..test_cases {
+        output.write_row([
+            params.value(rng, 0..=1_000_000_000),
+            params.value(rng, 0..=1_000_000_000),
+        ])?;
+        output.write_newline()?;
+    }
+    Ok(())
+}
+
+/// A sudoku board, which is valid half of the time.
+fn b5(rng: &mut StdRng, _: &Params, output: &mut dyn Write) -> io::Result<()> {
+    // Start from a valid board, then shuffle the digits, the rows within each band,
+    // the bands, the columns within each stack and the stacks, which keeps it valid.
+    let mut digits: Vec<u8> = (b'1'..=b'9').collect();
+    digits.shuffle(rng);
+    let shuffled = |rng: &mut StdRng| {
+        let mut groups = [0, 1, 2];
+        groups.shuffle(rng);
+        let mut order = Vec::new();
+        for group in groups {
+            let mut within = [0, 1, 2];
+            within.shuffle(rng);
+            order.extend(within.map(|i| group * 3 + i));
+        }
+        order
+    };
+    let (rows, columns) = (shuffled(rng), shuffled(rng));
+    let mut board: Vec<Vec<u8>> = rows
+        .iter()
+        .map(|&row| {
+            columns
+                .iter()
+                .map(|&column| digits[(row * 3 + row / 3 + column) % 9])
+                .collect()
+        })
+        .collect();
+
+    if rng.gen() {
+        // Changing one cell breaks its row, column and box.
+        let cell = &mut board[rng.gen_range(0..9)][rng.gen_range(0..9)];
+        *cell = b'1' + (*cell - b'1' + rng.gen_range(1..9)) % 9;
+    }
+
+    output.write_all(b"9 9\n")?;
+    board.iter().try_for_each(|row| {
+        output.write_all(row)?;
+        output.write_newline()
+    })
+}
+
+/// Test cases of a row of classrooms, each with at least one collection point.
+fn b6(rng: &mut StdRng, params: &Params, output: &mut dyn Write) -> io::Result<()> {
+    // A max test is one row of the most classrooms, unless more test cases are asked for.
+    let test_cases = if params.max_test && params.test_cases.is_none() {
+        1
+    } else {
+        params.test_cases(rng, solution::MAX_TEST_CASES)
+    };
+    output.write_u32(test_cases)?;
+    output.write_newline()?;
+
+    for _ in 0..test_cases {
+        let len = params.size(rng, 1..=500_000);
+        // Vary how sparse the collection points are between test cases.
+        let density = rng.gen_range(0.0..=1.0);
+        let mut classrooms: Vec<u8> = (0..len)
+            .map(|_| if rng.gen_bool(density) { b'1' } else { b'0' })
+            .collect();
+        if !classrooms.contains(&b'1') {
+            *classrooms.choose_mut(rng).unwrap() = b'1';
+        }
+
+        output.write_u32(len)?;
+        output.write_newline()?;
+        output.write_all(&classrooms)?;
+        output.write_newline()?;
+    }
+    Ok(())
+}
@@ -0,0 +1,68 @@
+use std::{fs::File, io, path::PathBuf};
+
+use anyhow::Context;
+
+#[derive(clap::Parser, Debug)]
+#[clap(author, version, about)]
+/// Random input generator for all SPCC Kickstart problems.
+///
+/// The generated input always follows the constraints of the statement.
+/// The same seed and options always give the same input.
+struct Args {
+    /// The problem to generate an input for, such as `a5`
+    problem: String,
+
+    /// The seed of the random number generator; random if not given
+    #[clap(long)]
+    seed: Option<u64>,
+
+    /// The number of test cases, for problems that have them
+    #[clap(long)]
+    test_cases: Option<u32>,
+
+    /// The number of numbers or characters, depending on the problem
+    #[clap(long)]
+    size: Option<u32>,
+
+    /// The largest number in the input
+    #[clap(long)]
+    max_value: Option<u32>,
+
+    /// Generate the largest input allowed, except for any size given
+    #[clap(long)]
+    max_test: bool,
+
+    /// Write the input to this file instead of standard output
+    #[clap(long, short)]
+    output: Option<PathBuf>,
+}
+
+fn main() -> anyhow::Result<()> {
+    let args = <Args as clap::Parser>::parse();
+
+    let generator =
+        gen::find(&args.problem).with_context(|| format!("Unknown problem `{}`", args.problem))?;
+    let params = gen::Params {
+        test_cases: args.test_cases,
+        size: args.size,
+        max_value: args.max_value,
+        max_test: args.max_test,
+    };
+
+    // Print the seed, so that the input can be generated again.
+    let seed = args.seed.unwrap_or_else(rand::random);
+    eprintln!("seed: {seed}");
+    let mut rng = <rand::rngs::StdRng as rand::SeedableRng>::seed_from_u64(seed);
+
+    let mut output: write_int::IntWriter<Box<dyn io::Write>> =
+        write_int::IntWriter::new(match &args.output {
+            Some(path) => Box::new(
+                File::create(path).with_context(|| format!("Cannot create {}", path.display()))?,
+            ),
+            None => Box::new(io::stdout().lock()),
+        });
+    (generator.generate)(&mut rng, &params, &mut output)?;
+    io::Write::flush(&mut output)?;
+
+    Ok(())
+}
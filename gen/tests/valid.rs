@@ -0,0 +1,139 @@
+use gen::Params;
+use rand::SeedableRng;
+
+type Validate = fn(&[u8]) -> Result<(), read_u32::ValidationError>;
+
+const VALIDATORS: [(&str, Validate); 12] = [
+    ("a1", |input| a1::validate(input)),
+    ("a2", |input| a2::validate(input)),
+    ("a3", |input| a3::validate(input)),
+    ("a4", |input| a4::validate(input)),
+    ("a5", |input| a5::validate(input)),
+    ("a6", |input| a6::validate(input)),
+    ("b1", |input| b1::validate(input)),
+    ("b2", |input| b2::validate(input)),
+    ("b3", |input| b3::validate(input)),
+    ("b4", |input| b4::validate(input)),
+    ("b5", |input| b5::validate(input)),
+    ("b6", |input| b6::validate(input)),
+];
+
+fn generate(name: &str, seed: u64, params: &Params) -> Vec<u8> {
+    let generator = gen::find(name).unwrap();
+    let mut rng = rand::rngs::StdRng::seed_from_u64(seed);
+    let mut input = Vec::new();
+    (generator.generate)(&mut rng, params, &mut input).unwrap();
+    input
+}
+
+fn assert_valid(params: &Params, seeds: std::ops::Range<u64>) {
+    for (name, validate) in VALIDATORS {
+        for seed in seeds.clone() {
+            let input = generate(name, seed, params);
+            if let Err(error) = validate(&input) {
+                panic!("{name} with seed {seed} and {params:?}: {error}");
+            }
+        }
+    }
+}
+
+#[test]
+fn all_problems() {
+    let names: Vec<_> = gen::GENERATORS
+        .iter()
+        .map(|generator| generator.name)
+        .collect();
+    let validated: Vec<_> = VALIDATORS.iter().map(|&(name, _)| name).collect();
+    assert_eq!(names, validated);
+}
+
+#[test]
+fn small() {
+    assert_valid(&Params::default(), 0..100);
+}
+
+#[test]
+fn max_test() {
+    let params = Params {
+        max_test: true,
+        ..Params::default()
+    };
+    assert_valid(&params, 0..1);
+}
+
+#[test]
+fn out_of_range() {
+    // Parameters beyond the constraints are clamped.
+    let params = Params {
+        test_cases: Some(0),
+        size: Some(u32::MAX),
+        max_value: Some(1),
+        max_test: false,
+    };
+    assert_valid(&params, 0..3);
+}
+
+#[test]
+fn reproducible() {
+    for generator in gen::GENERATORS {
+        let params = Params::default();
+        assert_eq!(
+            generate(generator.name, 7, &params),
+            generate(generator.name, 7, &params),
+        );
+    }
+}
+
+#[test]
+fn max_value() {
+    let params = Params {
+        test_cases: Some(50),
+        max_value: Some(3),
+        ..Params::default()
+    };
+    let input = generate("b4", 0, &params);
+    let numbers: Vec<u32> = String::from_utf8(input)
+        .unwrap()
+        .split_ascii_whitespace()
+        .skip(1)
+        .map(|n| n.parse().unwrap())
+        .collect();
+    assert_eq!(numbers.len(), 100);
+    assert!(numbers.iter().all(|&n| n <= 3));
+}
+
+#[test]
+fn a3_without_outer_spaces() {
+    for size in [1, 2, 3, 100] {
+        let params = Params {
+            size: Some(size),
+            ..Params::default()
+        };
+        for seed in 0..100 {
+            let input = generate("a3", seed, &params);
+            a3::validate(input.as_slice()).unwrap();
+            let line = input.strip_suffix(b"\n").unwrap();
+            assert!(
+                !line.starts_with(b" ") && !line.ends_with(b" "),
+                "seed {seed}: {:?}",
+                String::from_utf8_lossy(line)
+            );
+        }
+    }
+}
+
+#[test]
+fn both_sudoku_answers() {
+    let answers: Vec<_> = (0..20)
+        .map(|seed| {
+            let mut output = Vec::new();
+            b5::main_impl(
+                generate("b5", seed, &Params::default()).as_slice(),
+                &mut output,
+            );
+            output
+        })
+        .collect();
+    assert!(answers.contains(&b"1\n".to_vec()));
+    assert!(answers.contains(&b"0\n".to_vec()));
+}
@@ -0,0 +1,32 @@
+//! Checks that every problem runs within its time limit on its worst-case input,
+//! failing if any problem is too slow.
+//!
+//! Run with `cargo bench --bench time_limit`.
+
+use std::process::ExitCode;
+
+/// How many times each problem runs; the median time is taken.
+const RUNS: usize = 5;
+
+/// Returns the worst-case input of a problem.
+fn worst_case(name: &str) -> Vec<u8> {
+    let mut input = Vec::new();
+    gen::find(name).unwrap().worst_case(&mut input).unwrap();
+    input
+}
+
+fn main() -> ExitCode {
+    let mut all_within_limit = true;
+
+    for problem in kickstart::PROBLEMS {
+        let timing = kickstart::timing::time(problem, &worst_case(problem.name), RUNS);
+        println!("{timing}");
+        all_within_limit &= timing.is_within_limit();
+    }
+
+    if all_within_limit {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
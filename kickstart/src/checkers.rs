@@ -0,0 +1,55 @@
+//! Checkers for the problems that accept more than one correct output.
+//!
+//! These belong to the judge rather than to the solutions, so that the solutions don't depend on them.
+
+use kickstart_macros::Verifier;
+
+/// Accepts either output of Problem A6: `c` alone, or the triple `a b c` from [`a6::A6Witness`].
+///
+/// `c` must be the same as in the reference output,
+/// and a triple must be made of three different numbers of the input with `a * b = c`.
+pub const A6: Verifier<Check> = Verifier(a6);
+
+/// A checker function, taking the input, the output and the reference output.
+type Check = fn(&str, &str, &str) -> Result<(), String>;
+
+fn a6(input: &str, output: &str, reference: &str) -> Result<(), String> {
+    let parse = |text: &str| -> Result<Vec<i64>, String> {
+        text.split_ascii_whitespace()
+            .map(|token| {
+                token
+                    .parse()
+                    .map_err(|_| format!("`{token}` is not a number"))
+            })
+            .collect()
+    };
+    let output = parse(output)?;
+    let expected_c = parse(reference)?.last().copied();
+
+    let (witness, c) = match output[..] {
+        [c] => (None, c),
+        [a, b, c] => (Some((a, b)), c),
+        _ => return Err(format!("expected 1 or 3 numbers, found {}", output.len())),
+    };
+    if Some(c) != expected_c {
+        return Err(format!(
+            "expected c = {}, found {c}",
+            expected_c.unwrap_or(-1)
+        ));
+    }
+
+    if let Some((a, b)) = witness {
+        // Skip the count at the start of the input.
+        let mut remaining: Vec<_> = parse(input)?.into_iter().skip(1).collect();
+        for n in [a, b, c] {
+            let index = remaining.iter().position(|&m| m == n).ok_or_else(|| {
+                format!("{a} {b} {c} uses {n} more times than it is in the input")
+            })?;
+            remaining.swap_remove(index);
+        }
+        if a.checked_mul(b) != Some(c) {
+            return Err(format!("{a} * {b} is not {c}"));
+        }
+    }
+    Ok(())
+}
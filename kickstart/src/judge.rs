@@ -0,0 +1,267 @@
+//! Running a problem over a directory of test cases, like an online judge.
+
+use std::{
+    any::Any,
+    fmt, fs, io,
+    panic::{self, AssertUnwindSafe},
+    path::Path,
+    sync::{mpsc, Arc},
+    thread,
+    time::{Duration, Instant},
+};
+
+use kickstart_macros::{CheckResult, Checker, Comparison};
+use serde::{Serialize, Serializer};
+
+use crate::Problem;
+
+/// The result of running a problem on a test case.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+pub enum Verdict {
+    /// Accepted: the output is correct.
+    #[serde(rename = "AC")]
+    Accepted,
+    /// Wrong Answer: the output is not correct.
+    #[serde(rename = "WA")]
+    WrongAnswer,
+    /// Time Limit Exceeded: the program didn't finish in time.
+    #[serde(rename = "TLE")]
+    TimeLimitExceeded,
+    /// Runtime Error: the program panicked.
+    #[serde(rename = "RE")]
+    RuntimeError,
+}
+
+impl Verdict {
+    /// All verdicts, from the best to the worst.
+    pub const ALL: [Self; 4] = [
+        Self::Accepted,
+        Self::WrongAnswer,
+        Self::TimeLimitExceeded,
+        Self::RuntimeError,
+    ];
+
+    /// Returns the usual abbreviation of the verdict, such as `"AC"`.
+    #[must_use]
+    pub const fn abbreviation(self) -> &'static str {
+        match self {
+            Self::Accepted => "AC",
+            Self::WrongAnswer => "WA",
+            Self::TimeLimitExceeded => "TLE",
+            Self::RuntimeError => "RE",
+        }
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.abbreviation())
+    }
+}
+
+/// The result of a test case.
+#[derive(Clone, Debug, Serialize)]
+pub struct CaseResult {
+    /// The name of the test case, which is the name of its files without the extension.
+    pub name: String,
+    pub verdict: Verdict,
+    /// The wall-clock time taken, or the time limit if it was exceeded.
+    #[serde(rename = "time_ms", serialize_with = "serialize_millis")]
+    pub time: Duration,
+    /// What went wrong, for a wrong answer or a runtime error.
+    pub message: Option<String>,
+}
+
+/// The results of all test cases of a problem.
+#[derive(Clone, Debug, Serialize)]
+pub struct Report {
+    /// The name of the problem, such as `"a5"`.
+    pub problem: &'static str,
+    pub cases: Vec<CaseResult>,
+}
+
+impl Report {
+    /// Returns the number of test cases with the given verdict.
+    #[must_use]
+    pub fn count(&self, verdict: Verdict) -> usize {
+        self.cases
+            .iter()
+            .filter(|case| case.verdict == verdict)
+            .count()
+    }
+
+    /// Returns `true` if every test case is accepted.
+    #[must_use]
+    pub fn all_accepted(&self) -> bool {
+        self.count(Verdict::Accepted) == self.cases.len()
+    }
+}
+
+impl fmt::Display for Report {
+    /// Shows the results as a table, followed by a summary.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let width = self
+            .cases
+            .iter()
+            .map(|case| case.name.len())
+            .chain([4])
+            .max()
+            .unwrap_or_default();
+
+        writeln!(f, "{:<width$}  verdict  time (ms)", "case")?;
+        for case in &self.cases {
+            write!(
+                f,
+                "{:<width$}  {:<7}  {:>9.1}",
+                case.name,
+                case.verdict,
+                case.time.as_secs_f64() * 1000.0,
+            )?;
+            // Only the first line; the rest is in the JSON report.
+            if let Some(message) = case.message.as_deref().and_then(|m| m.lines().next()) {
+                write!(f, "  {message}")?;
+            }
+            writeln!(f)?;
+        }
+
+        writeln!(f)?;
+        let summary: Vec<_> = Verdict::ALL
+            .iter()
+            .map(|&verdict| format!("{verdict}: {}", self.count(verdict)))
+            .collect();
+        write!(
+            f,
+            "{problem}: {summary} (total: {total})",
+            problem = self.problem,
+            summary = summary.join(", "),
+            total = self.cases.len(),
+        )
+    }
+}
+
+/// How to judge the test cases.
+#[derive(Clone, Copy, Debug)]
+pub struct Options {
+    /// The wall-clock time limit of each test case.
+    pub time_limit: Duration,
+    /// How the output is compared with the expected output,
+    /// unless the problem has a checker of its own.
+    pub comparison: Comparison,
+}
+
+/// The name of the threads that [`judge_case`] runs problems on,
+/// so that a panic hook can tell the panics of a problem from those of the judge itself.
+pub const WORKER_THREAD_NAME: &str = "judge worker";
+
+/// Runs `problem` on each pair of `NAME.in` and `NAME.out` files in `dir`, in order of name.
+///
+/// # Errors
+///
+/// Returns an error if the directory or any test case cannot be read,
+/// or if an input file has no matching output file.
+pub fn judge_dir(problem: &Problem, dir: &Path, options: Options) -> io::Result<Report> {
+    let mut names = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.extension().is_some_and(|extension| extension == "in") {
+            if let Some(name) = path.file_stem().and_then(|name| name.to_str()) {
+                names.push(name.to_owned());
+            }
+        }
+    }
+    names.sort_unstable();
+
+    let cases = names
+        .into_iter()
+        .map(|name| {
+            let input = fs::read(dir.join(format!("{name}.in")))?;
+            let expected = fs::read_to_string(dir.join(format!("{name}.out")))
+                .map_err(|e| io::Error::new(e.kind(), format!("cannot read {name}.out: {e}")))?;
+            Ok(judge_case(problem, name, input, &expected, options))
+        })
+        .collect::<io::Result<_>>()?;
+
+    Ok(Report {
+        problem: problem.name,
+        cases,
+    })
+}
+
+/// Runs `problem` on a single test case.
+///
+/// The output is checked with the checker of the problem, if it has one,
+/// with `expected` as the reference output.
+///
+/// The problem runs on a thread of its own, named [`WORKER_THREAD_NAME`].
+/// If it exceeds the time limit, that thread is left running in the background,
+/// as there is no way to stop it.
+///
+/// # Panics
+///
+/// Panics if the thread cannot be created.
+#[must_use]
+pub fn judge_case(
+    problem: &Problem,
+    name: String,
+    input: Vec<u8>,
+    expected: &str,
+    options: Options,
+) -> CaseResult {
+    let main = problem.main;
+    let input: Arc<[u8]> = input.into();
+    let (sender, receiver) = mpsc::channel();
+
+    let start = Instant::now();
+    thread::Builder::new()
+        .name(WORKER_THREAD_NAME.into())
+        .spawn({
+            let input = Arc::clone(&input);
+            move || {
+                let mut output = Vec::new();
+                let result = panic::catch_unwind(AssertUnwindSafe(|| {
+                    main(&mut &input[..], &mut output);
+                }));
+                // The judge may have given up waiting already.
+                let _ = sender.send((result.map(|()| output), start.elapsed()));
+            }
+        })
+        .expect("Cannot start a thread to run the problem on");
+
+    let (verdict, time, message) = match receiver.recv_timeout(options.time_limit) {
+        Ok((Ok(output), time)) => {
+            let checker = problem
+                .checker
+                .map_or(&options.comparison as &dyn Checker, |checker| checker);
+            match checker.check(
+                &String::from_utf8_lossy(&input),
+                &String::from_utf8_lossy(&output),
+                expected,
+            ) {
+                CheckResult::Accepted => (Verdict::Accepted, time, None),
+                CheckResult::WrongAnswer(reason) => (Verdict::WrongAnswer, time, Some(reason)),
+            }
+        }
+        Ok((Err(payload), time)) => (Verdict::RuntimeError, time, Some(panic_message(&*payload))),
+        Err(_) => (Verdict::TimeLimitExceeded, options.time_limit, None),
+    };
+
+    CaseResult {
+        name,
+        verdict,
+        time,
+        message,
+    }
+}
+
+/// Returns the message of a panic.
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(|message| (*message).to_owned())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "panicked".into())
+}
+
+fn serialize_millis<S: Serializer>(time: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_f64(time.as_secs_f64() * 1000.0)
+}
@@ -0,0 +1,108 @@
+//! A registry of the solutions to all problems, for tools that work over every problem.
+
+use std::{fmt, io::prelude::*, time::Duration};
+
+use kickstart_macros::Checker;
+use solution::Solution;
+
+pub mod checkers;
+pub mod judge;
+pub mod timing;
+
+/// The time limit of every problem.
+///
+/// The statements that this repository was written against are not kept here,
+/// so no problem has a limit of its own; 1 second is the usual limit of online judges.
+pub const TIME_LIMIT: Duration = Duration::from_secs(1);
+
+/// The signature of a package's `main_impl`, taking the input and the output.
+pub type Main = fn(&mut dyn BufRead, &mut dyn Write);
+
+/// A problem with a solution in this repository.
+#[derive(Clone, Copy)]
+pub struct Problem {
+    /// The lowercase problem number, which is also the name of its package, such as `"a5"`.
+    pub name: &'static str,
+    /// The description of the package, which gives the title of the problem
+    /// and a summary of its input and output.
+    pub description: &'static str,
+    /// The `main_impl` of the package.
+    pub main: Main,
+    /// The time limit, which is [`TIME_LIMIT`] for every problem in [`PROBLEMS`].
+    pub time_limit: Duration,
+    /// How to check an output, if the problem allows more than one correct output.
+    pub checker: Option<&'static (dyn Checker + Sync)>,
+}
+
+impl fmt::Debug for Problem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Problem")
+            .field("name", &self.name)
+            .field("description", &self.description)
+            .field("time_limit", &self.time_limit)
+            .field("checker", &self.checker.map(|_| ..))
+            .finish_non_exhaustive()
+    }
+}
+
+impl Problem {
+    /// Returns the title of the problem and the summary of its input and output,
+    /// without the `Solution for SPCC Kickstart Problem XX` at the start of the description.
+    #[must_use]
+    pub fn summary(&self) -> &'static str {
+        self.description
+            .split_once(", ")
+            .map_or(self.description, |(_, summary)| summary)
+    }
+
+    /// Reads the input of the problem from `input` and writes the answer to `output`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the input is malformed or if any I/O error occured.
+    pub fn run(&self, mut input: impl BufRead, mut output: impl Write) {
+        (self.main)(&mut input, &mut output);
+    }
+}
+
+/// Lists the problems, each as `package::Solution`,
+/// followed by `=> &checkers::XX` if it has a checker of its own.
+macro_rules! problems {
+    ($($package:ident::$solution:ident $(=> $checker:expr)?),* $(,)?) => {
+        /// All problems, in order.
+        pub const PROBLEMS: &[Problem] = &[$(
+            Problem {
+                name: stringify!($package),
+                description: <$package::$solution as Solution>::DESCRIPTION,
+                main: |input, output| $package::main_impl(input, output),
+                time_limit: TIME_LIMIT,
+                checker: problems!(@checker $($checker)?),
+            },
+        )*];
+    };
+    (@checker) => { None };
+    (@checker $checker:expr) => { Some($checker) };
+}
+
+problems![
+    a1::A1,
+    a2::A2,
+    a3::A3,
+    a4::A4,
+    a5::A5,
+    a6::A6 => &checkers::A6,
+    b1::B1,
+    b2::B2,
+    b3::B3,
+    b4::B4,
+    b5::B5,
+    b6::B6,
+];
+
+/// Returns the problem with the given name, ignoring case.
+#[must_use]
+pub fn find(name: &str) -> Option<&'static Problem> {
+    PROBLEMS
+        .iter()
+        .find(|problem| problem.name.eq_ignore_ascii_case(name))
+}
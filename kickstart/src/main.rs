@@ -0,0 +1,141 @@
+use std::{io, panic, path::PathBuf, process::ExitCode, thread, time::Duration};
+
+use anyhow::{bail, Context};
+use kickstart::judge;
+use kickstart_macros::Comparison;
+
+#[derive(clap::Parser, Debug)]
+#[clap(author, version, about)]
+/// Solutions to all SPCC Kickstart problems.
+///
+/// Run `kickstart <problem>`, such as `kickstart a5`, to solve a problem
+/// with the input from standard input. Add `--witness` to also print
+/// why the answer is right, for the problems that can.
+enum Command {
+    /// List all problems, with a summary of their input and output
+    List,
+    /// Run a problem over a directory of test cases and report a verdict for each
+    Judge(JudgeArgs),
+    #[clap(external_subcommand)]
+    Problem(Vec<String>),
+}
+
+#[derive(clap::Args, Debug)]
+struct JudgeArgs {
+    /// The problem to run, such as `a5`
+    problem: String,
+
+    /// The directory with the test cases, as pairs of `NAME.in` and `NAME.out` files
+    dir: PathBuf,
+
+    /// The time limit of each test case, in seconds; 1 by default, like every problem
+    #[clap(long)]
+    time_limit: Option<f64>,
+
+    /// How the output is compared with the expected output, for problems without a checker
+    #[clap(long, default_value = "lines", possible_values = ["exact", "tokens", "lines", "float"])]
+    compare: String,
+
+    /// The largest absolute or relative error of a number with `--compare float`
+    #[clap(long, default_value_t = 1e-6)]
+    tolerance: f64,
+
+    /// Print the report as JSON instead of a table
+    #[clap(long)]
+    json: bool,
+}
+
+/// Entry point.
+fn main() -> anyhow::Result<ExitCode> {
+    match <Command as clap::Parser>::parse() {
+        Command::List => {
+            for problem in kickstart::PROBLEMS {
+                println!("{:<4}{}", problem.name, problem.summary());
+            }
+            Ok(ExitCode::SUCCESS)
+        }
+        Command::Judge(args) => run_judge(&args),
+        Command::Problem(args) => {
+            let (name, witness) = match args.as_slice() {
+                [name] => (name, false),
+                [name, flag] if flag == "--witness" => (name, true),
+                _ => bail!("`kickstart <problem>` takes no other arguments than `--witness`"),
+            };
+            let problem = find(name)?;
+            let main = if witness {
+                WITNESSES
+                    .iter()
+                    .find(|&&(name, _)| name == problem.name)
+                    .map(|&(_, main)| main)
+                    .with_context(|| format!("Problem `{}` has no witness", problem.name))?
+            } else {
+                problem.main
+            };
+
+            // Reading everything at once is never slower than reading it bit by bit.
+            let input = read_u32::slurp(io::stdin().lock()).context("An input error occured")?;
+            main(
+                &mut input.as_slice(),
+                &mut write_int::IntWriter::new(io::stdout().lock()),
+            );
+            Ok(ExitCode::SUCCESS)
+        }
+    }
+}
+
+/// The problems that can print why their answer is right with `--witness`,
+/// with the `main_impl` that does so.
+const WITNESSES: &[(&str, kickstart::Main)] =
+    &[("a6", |input, output| a6::main_impl_witness(input, output))];
+
+fn run_judge(args: &JudgeArgs) -> anyhow::Result<ExitCode> {
+    let problem = find(&args.problem)?;
+    if args.tolerance.is_nan() || args.tolerance < 0.0 {
+        bail!("The tolerance must be a non-negative number");
+    }
+    let options = judge::Options {
+        time_limit: match args.time_limit {
+            Some(seconds) => Duration::try_from_secs_f64(seconds)
+                .context("The time limit must be a non-negative number of seconds")?,
+            None => problem.time_limit,
+        },
+        comparison: match args.compare.as_str() {
+            "exact" => Comparison::Exact,
+            "tokens" => Comparison::Tokens,
+            "float" => Comparison::Float {
+                abs: args.tolerance,
+                rel: args.tolerance,
+            },
+            _ => Comparison::Lines,
+        },
+    };
+
+    // The panics of the problem are reported as runtime errors; don't print them as well.
+    // Any other panic is a bug in the judge, which should still be printed.
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        if thread::current().name() != Some(judge::WORKER_THREAD_NAME) {
+            hook(info);
+        }
+    }));
+    let report = judge::judge_dir(problem, &args.dir, options)
+        .with_context(|| format!("Cannot read the test cases in {}", args.dir.display()))?;
+
+    if args.json {
+        println!("{}", serde_json::to_string_pretty(&report)?);
+    } else {
+        println!("{report}");
+    }
+
+    Ok(if report.all_accepted() {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    })
+}
+
+fn find(name: &str) -> anyhow::Result<&'static kickstart::Problem> {
+    kickstart::find(name).with_context(|| {
+        format!("Unknown problem `{name}`; run `kickstart list` to see all problems")
+    })
+}
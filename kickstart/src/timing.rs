@@ -0,0 +1,87 @@
+//! Measuring how long a problem takes, to check it against its time limit.
+
+use std::{
+    fmt,
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use crate::Problem;
+
+/// The fraction of the time limit that a problem may take here.
+///
+/// The judge may be slower than the machine running the check,
+/// and its time includes starting the program, so only half of the limit is allowed.
+pub const SAFETY_FACTOR: f64 = 0.5;
+
+/// How long a problem takes on an input.
+#[derive(Clone, Copy, Debug)]
+pub struct Timing {
+    /// The name of the problem, such as `"a5"`.
+    pub problem: &'static str,
+    /// The median time of all runs.
+    pub median: Duration,
+    /// The time limit of the problem.
+    pub time_limit: Duration,
+}
+
+impl Timing {
+    /// Returns the time that the problem may take, which is the time limit times [`SAFETY_FACTOR`].
+    #[must_use]
+    pub fn allowed(&self) -> Duration {
+        self.time_limit.mul_f64(SAFETY_FACTOR)
+    }
+
+    /// Returns `true` if the median time is within the allowed time.
+    #[must_use]
+    pub fn is_within_limit(&self) -> bool {
+        self.median <= self.allowed()
+    }
+}
+
+impl fmt::Display for Timing {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let millis = |time: Duration| time.as_secs_f64() * 1000.0;
+        write!(
+            f,
+            "{problem}: {median:.1} ms of {allowed:.1} ms allowed ({verdict})",
+            problem = self.problem,
+            median = millis(self.median),
+            allowed = millis(self.allowed()),
+            verdict = if self.is_within_limit() {
+                "ok"
+            } else {
+                "too slow"
+            },
+        )
+    }
+}
+
+/// Runs `problem` on `input` for `runs` times, including parsing the input and writing the output,
+/// and returns the median time.
+///
+/// # Panics
+///
+/// Panics if `runs` is 0, or if the problem panics.
+#[must_use]
+pub fn time(problem: &Problem, input: &[u8], runs: usize) -> Timing {
+    assert!(runs > 0, "the problem must run at least once");
+
+    let mut times: Vec<Duration> = (0..runs)
+        .map(|_| {
+            let mut output = Vec::new();
+            let start = Instant::now();
+            problem.run(black_box(input), &mut output);
+            let time = start.elapsed();
+            black_box(output);
+            time
+        })
+        .collect();
+    times.sort_unstable();
+
+    Timing {
+        problem: problem.name,
+        median: times[runs / 2],
+        time_limit: problem.time_limit,
+    }
+}
@@ -0,0 +1,28 @@
+use kickstart_macros::Checker;
+
+#[test]
+fn a6() {
+    let input = "6\n2 2 4 4 16 23\n";
+    let check = |output| {
+        kickstart::checkers::A6
+            .check(input, output, "16\n")
+            .is_accepted()
+    };
+    assert!(check("16\n"));
+    assert!(check("4 4 16\n"));
+    assert!(!check("4\n"));
+    assert!(!check("2 2 4\n"));
+    // 2 * 8 is 16, but 8 is not in the input.
+    assert!(!check("2 8 16\n"));
+    // There is only one 16.
+    assert!(!check("16 1 16\n"));
+    assert!(!check("4 16\n"));
+
+    // Both numbers may be the same, but only if the input has them twice.
+    assert!(!kickstart::checkers::A6
+        .check("3\n2 4 4\n", "2 2 4\n", "4\n")
+        .is_accepted());
+    assert!(kickstart::checkers::A6
+        .check("3\n2 2 4\n", "2 2 4\n", "4\n")
+        .is_accepted());
+}
@@ -0,0 +1,118 @@
+use std::{fs, time::Duration};
+
+use kickstart::{
+    judge::{self, Options, Verdict},
+    Problem,
+};
+use kickstart_macros::{Comparison, Verifier};
+
+const OPTIONS: Options = Options {
+    time_limit: Duration::from_secs(5),
+    comparison: Comparison::Lines,
+};
+
+fn a5() -> &'static Problem {
+    kickstart::find("a5").unwrap()
+}
+
+fn judge_a5(input: &str, expected: &str) -> judge::CaseResult {
+    judge::judge_case(a5(), "case".into(), input.into(), expected, OPTIONS)
+}
+
+#[test]
+fn accepted() {
+    let result = judge_a5("2\n2 10\n1 1\n", "2\n1\n");
+    assert_eq!(result.verdict, Verdict::Accepted);
+    assert_eq!(result.message, None);
+}
+
+#[test]
+fn wrong_answer() {
+    let result = judge_a5("1\n2 10\n", "3\n");
+    assert_eq!(result.verdict, Verdict::WrongAnswer);
+    assert!(result.message.unwrap().contains("expected `3`"));
+}
+
+#[test]
+fn runtime_error() {
+    let result = judge_a5("one\n", "1\n");
+    assert_eq!(result.verdict, Verdict::RuntimeError);
+    assert!(result
+        .message
+        .unwrap()
+        .starts_with("Failed to read the input"));
+}
+
+#[test]
+fn time_limit_exceeded() {
+    let slow = Problem {
+        name: "slow",
+        description: "Sleeps",
+        main: |_, _| std::thread::sleep(Duration::from_secs(1)),
+        time_limit: Duration::from_millis(10),
+        checker: None,
+    };
+    let options = Options {
+        time_limit: Duration::from_millis(10),
+        ..OPTIONS
+    };
+
+    let result = judge::judge_case(&slow, "case".into(), Vec::new(), "", options);
+    assert_eq!(result.verdict, Verdict::TimeLimitExceeded);
+    assert_eq!(result.time, options.time_limit);
+}
+
+#[test]
+fn checker() {
+    // Any multiple of the input is correct.
+    type Verify = fn(&str, &str, &str) -> Result<(), String>;
+    static MULTIPLE: Verifier<Verify> = Verifier(|input, output, _| {
+        let n: u32 = input.trim().parse().unwrap();
+        match output.trim().parse::<u32>() {
+            Ok(m) if m % n == 0 => Ok(()),
+            _ => Err(format!("{} is not a multiple of {n}", output.trim())),
+        }
+    });
+    let double = Problem {
+        name: "double",
+        description: "Prints twice the input",
+        main: |input, output| {
+            let mut line = String::new();
+            input.read_line(&mut line).unwrap();
+            writeln!(output, "{}", line.trim().parse::<u32>().unwrap() * 2).unwrap();
+        },
+        time_limit: Duration::from_secs(1),
+        checker: Some(&MULTIPLE),
+    };
+
+    // The reference output differs, but the checker accepts both.
+    let result = judge::judge_case(&double, "case".into(), b"3\n".to_vec(), "3\n", OPTIONS);
+    assert_eq!(result.verdict, Verdict::Accepted);
+
+    // The checker only runs if the program finishes.
+    let result = judge::judge_case(&double, "case".into(), b"x\n".to_vec(), "", OPTIONS);
+    assert_eq!(result.verdict, Verdict::RuntimeError);
+}
+
+#[test]
+fn judge_dir() {
+    let dir = std::env::temp_dir().join(format!("kickstart_judge_{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    for (name, input, output) in [("1", "1\n2 10\n", "2\n"), ("2", "1\n1 1\n", "2\n")] {
+        fs::write(dir.join(format!("{name}.in")), input).unwrap();
+        fs::write(dir.join(format!("{name}.out")), output).unwrap();
+    }
+
+    let report = judge::judge_dir(a5(), &dir, OPTIONS).unwrap();
+    fs::remove_dir_all(&dir).unwrap();
+
+    let verdicts: Vec<_> = report.cases.iter().map(|case| case.verdict).collect();
+    assert_eq!(verdicts, [Verdict::Accepted, Verdict::WrongAnswer]);
+    assert!(!report.all_accepted());
+    assert!(report
+        .to_string()
+        .ends_with("a5: AC: 1, WA: 1, TLE: 0, RE: 0 (total: 2)"));
+
+    let json: serde_json::Value = serde_json::to_value(&report).unwrap();
+    assert_eq!(json["cases"][1]["verdict"], "WA");
+}
@@ -0,0 +1,42 @@
+#[test]
+fn all_problems() {
+    let names: Vec<_> = kickstart::PROBLEMS
+        .iter()
+        .map(|problem| problem.name)
+        .collect();
+    assert_eq!(
+        names,
+        ["a1", "a2", "a3", "a4", "a5", "a6", "b1", "b2", "b3", "b4", "b5", "b6"]
+    );
+}
+
+#[test]
+fn summaries() {
+    for problem in kickstart::PROBLEMS {
+        assert!(
+            !problem.summary().starts_with("Solution"),
+            "{}",
+            problem.name
+        );
+        assert!(problem.summary().contains("prints"), "{}", problem.name);
+    }
+}
+
+#[test]
+fn find() {
+    assert_eq!(
+        kickstart::find("B5").map(|problem| problem.name),
+        Some("b5")
+    );
+    assert!(kickstart::find("c1").is_none());
+    assert!(kickstart::find("list").is_none());
+}
+
+#[test]
+fn run() {
+    let mut output = Vec::new();
+    kickstart::find("a5")
+        .unwrap()
+        .run(&b"2\n2 10\n1 1\n"[..], &mut output);
+    assert_eq!(output, b"2\n1\n");
+}
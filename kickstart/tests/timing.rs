@@ -0,0 +1,57 @@
+use std::time::Duration;
+
+use kickstart::{timing, Problem};
+
+fn sleeping(time_limit: Duration) -> Problem {
+    Problem {
+        name: "sleep",
+        description: "Sleeps for 20 milliseconds",
+        main: |_, _| std::thread::sleep(Duration::from_millis(20)),
+        time_limit,
+        checker: None,
+    }
+}
+
+#[test]
+fn within_limit() {
+    let timing = timing::time(&sleeping(Duration::from_secs(10)), b"", 3);
+    assert!(timing.median >= Duration::from_millis(20));
+    assert!(timing.is_within_limit());
+    assert!(timing.to_string().ends_with("of 5000.0 ms allowed (ok)"));
+}
+
+#[test]
+fn too_slow() {
+    // Within the time limit itself, but not with the safety factor.
+    let timing = timing::time(&sleeping(Duration::from_millis(30)), b"", 3);
+    assert!(!timing.is_within_limit());
+    assert!(timing.to_string().ends_with("(too slow)"));
+}
+
+#[test]
+fn limits() {
+    for problem in kickstart::PROBLEMS {
+        assert_eq!(
+            problem.time_limit,
+            kickstart::TIME_LIMIT,
+            "{}",
+            problem.name
+        );
+    }
+}
+
+// Too slow without optimizations; run with `cargo test --release -- --ignored`.
+#[test]
+#[ignore = "needs a release build"]
+fn worst_cases() {
+    for problem in kickstart::PROBLEMS {
+        let mut input = Vec::new();
+        gen::find(problem.name)
+            .unwrap()
+            .worst_case(&mut input)
+            .unwrap();
+
+        let timing = timing::time(problem, &input, 3);
+        assert!(timing.is_within_limit(), "{timing}");
+    }
+}
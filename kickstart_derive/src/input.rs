@@ -0,0 +1,175 @@
+//! The derive macro behind `read_u32::KickstartInput`.
+//!
+//! This only depends on `syn`, `quote` and `proc_macro2`, not on `proc_macro`,
+//! so the `bundle` tool includes this file to expand the derive itself.
+
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{
+    spanned::Spanned, Data, DeriveInput, Error, Expr, ExprLit, Field, Fields, Ident, Lit, LitStr,
+};
+
+/// The delimiter after a field.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Sep {
+    Space,
+    Newline,
+}
+
+/// Where the length of a `Vec` field comes from.
+enum Count {
+    /// The earlier field given by `#[count_from = "..."]`.
+    From(Ident),
+    /// A line of its own just before the field, given by `#[count_prefixed]`.
+    Prefixed,
+}
+
+/// How a field is laid out in the input, according to its attributes.
+struct Layout {
+    /// The delimiter explicitly given by `#[sep = "..."]` or `#[line]`.
+    sep: Option<Sep>,
+    /// The length of the field, if it is a `Vec`.
+    count: Option<Count>,
+}
+
+pub fn expand(input: &DeriveInput) -> syn::Result<TokenStream> {
+    let Data::Struct(data) = &input.data else {
+        return Err(Error::new(
+            input.span(),
+            "`KickstartInput` can only be derived for structs",
+        ));
+    };
+    let Fields::Named(fields) = &data.fields else {
+        return Err(Error::new(
+            data.fields.span(),
+            "`KickstartInput` can only be derived for structs with named fields",
+        ));
+    };
+
+    let field_count = fields.named.len();
+    let reads = fields
+        .named
+        .iter()
+        .enumerate()
+        .map(|(i, field)| read_field(field, i + 1 == field_count))
+        .collect::<syn::Result<Vec<_>>>()?;
+    let names = fields.named.iter().map(|field| &field.ident);
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::read_u32::KickstartInput for #name #ty_generics #where_clause {
+            fn try_read_from<R: ::std::io::BufRead>(
+                __reader: &mut ::read_u32::U32Reader<R>,
+            ) -> ::core::result::Result<Self, ::read_u32::ReadError> {
+                #(#reads)*
+                ::core::result::Result::Ok(Self { #(#names),* })
+            }
+        }
+    })
+}
+
+/// Generates a `let` statement that reads the field into a variable of the same name.
+fn read_field(field: &Field, is_last: bool) -> syn::Result<TokenStream> {
+    let Layout { sep, count } = parse_layout(field)?;
+    let name = &field.ident;
+    let ty = &field.ty;
+
+    let (prefix, count) = match count {
+        Some(Count::From(count)) => (None, Some(quote! { #count })),
+        Some(Count::Prefixed) => (
+            Some(quote! { let __count: usize = __reader.try_read_until_newline()?; }),
+            Some(quote! { __count }),
+        ),
+        None => (None, None),
+    };
+
+    let read = match (count, sep) {
+        // A row of numbers on a line of its own.
+        (Some(count), Some(Sep::Newline)) => quote! {
+            __reader.try_read_row(#count)?
+        },
+        // Some inputs, one after another.
+        (Some(count), None) => quote! {
+            (0..#count)
+                .map(|_| ::read_u32::KickstartInput::try_read_from(__reader))
+                .collect::<::core::result::Result<#ty, _>>()?
+        },
+        (Some(_), Some(Sep::Space)) => {
+            return Err(Error::new(
+                field.span(),
+                "a field with a count must be followed by a newline",
+            ))
+        }
+        // A single number; the last field ends the line unless told otherwise.
+        (None, sep) => match sep.unwrap_or(if is_last { Sep::Newline } else { Sep::Space }) {
+            Sep::Space => quote! { __reader.try_read_until_space()? },
+            Sep::Newline => quote! { __reader.try_read_until_newline()? },
+        },
+    };
+
+    Ok(quote! { let #name: #ty = { #prefix #read }; })
+}
+
+fn parse_layout(field: &Field) -> syn::Result<Layout> {
+    let mut layout = Layout {
+        sep: None,
+        count: None,
+    };
+
+    for attr in &field.attrs {
+        if attr.path().is_ident("line") {
+            attr.meta.require_path_only()?;
+            set_sep(&mut layout, Sep::Newline, attr.span())?;
+        } else if attr.path().is_ident("sep") {
+            let sep = match string_value(attr)?.value().as_str() {
+                "space" => Sep::Space,
+                "newline" => Sep::Newline,
+                _ => {
+                    return Err(Error::new(
+                        attr.span(),
+                        r#"expected `#[sep = "space"]` or `#[sep = "newline"]`"#,
+                    ))
+                }
+            };
+            set_sep(&mut layout, sep, attr.span())?;
+        } else if attr.path().is_ident("count_from") {
+            set_count(
+                &mut layout,
+                Count::From(string_value(attr)?.parse()?),
+                attr.span(),
+            )?;
+        } else if attr.path().is_ident("count_prefixed") {
+            attr.meta.require_path_only()?;
+            set_count(&mut layout, Count::Prefixed, attr.span())?;
+        }
+    }
+
+    Ok(layout)
+}
+
+fn set_sep(layout: &mut Layout, sep: Sep, span: Span) -> syn::Result<()> {
+    if layout.sep.replace(sep).is_some() {
+        return Err(Error::new(span, "the delimiter is given more than once"));
+    }
+    Ok(())
+}
+
+fn set_count(layout: &mut Layout, count: Count, span: Span) -> syn::Result<()> {
+    if layout.count.replace(count).is_some() {
+        return Err(Error::new(span, "the count is given more than once"));
+    }
+    Ok(())
+}
+
+/// Returns the string in an attribute like `#[name = "string"]`.
+fn string_value(attr: &syn::Attribute) -> syn::Result<&LitStr> {
+    match &attr.meta.require_name_value()?.value {
+        Expr::Lit(ExprLit {
+            lit: Lit::Str(string),
+            ..
+        }) => Ok(string),
+        other => Err(Error::new(other.span(), "expected a string literal")),
+    }
+}
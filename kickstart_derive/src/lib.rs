@@ -0,0 +1,132 @@
+//! Procedural macros: the derive macro behind `read_u32::KickstartInput`,
+//! and `sample_tests!`, which is re-exported by `kickstart_macros`.
+
+use std::path::Path;
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{
+    parse::{Parse, ParseStream},
+    DeriveInput, Error, Expr, Ident, LitStr, Token,
+};
+
+mod input;
+
+/// Derives `read_u32::KickstartInput` for a struct with named fields.
+#[proc_macro_derive(KickstartInput, attributes(sep, count_from, count_prefixed, line))]
+pub fn derive_kickstart_input(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = syn::parse_macro_input!(input as DeriveInput);
+    input::expand(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// Generates a test for each pair of `NAME.in` and `NAME.out` files in a directory.
+///
+/// See `kickstart_macros::sample_tests!`.
+#[proc_macro]
+pub fn sample_tests(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let args = syn::parse_macro_input!(input as SampleTestsArgs);
+    expand_sample_tests(&args)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// The arguments of `sample_tests!`: a package name, a directory,
+/// and optionally how to compare the outputs.
+struct SampleTestsArgs {
+    package: Ident,
+    dir: LitStr,
+    comparison: Option<Expr>,
+}
+
+impl Parse for SampleTestsArgs {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let package = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let dir = input.parse()?;
+        let comparison = if input.parse::<Option<Token![,]>>()?.is_some() && !input.is_empty() {
+            let comparison = input.parse()?;
+            input.parse::<Option<Token![,]>>()?;
+            Some(comparison)
+        } else {
+            None
+        };
+        Ok(Self {
+            package,
+            dir,
+            comparison,
+        })
+    }
+}
+
+fn expand_sample_tests(args: &SampleTestsArgs) -> syn::Result<TokenStream> {
+    let error = |message: String| Error::new(args.dir.span(), message);
+
+    // Relative paths are relative to the package, like in `Cargo.toml`.
+    let manifest_dir = std::env::var("CARGO_MANIFEST_DIR")
+        .map_err(|_| error("`CARGO_MANIFEST_DIR` is not set".into()))?;
+    let dir = Path::new(&manifest_dir).join(args.dir.value());
+
+    let mut names = Vec::new();
+    for entry in std::fs::read_dir(&dir)
+        .map_err(|e| error(format!("cannot read {dir}: {e}", dir = dir.display())))?
+    {
+        let path = entry
+            .map_err(|e| error(format!("cannot read {dir}: {e}", dir = dir.display())))?
+            .path();
+        if path.extension().is_some_and(|extension| extension == "in") {
+            if let Some(name) = path.file_stem().and_then(|name| name.to_str()) {
+                names.push(name.to_owned());
+            }
+        }
+    }
+    // Keep the order of the tests stable.
+    names.sort_unstable();
+
+    let package = &args.package;
+    let comparison = args.comparison.iter();
+    let tests = names
+        .iter()
+        .map(|name| {
+            let input = dir.join(format!("{name}.in"));
+            let output = dir.join(format!("{name}.out"));
+            if !output.is_file() {
+                return Err(error(format!(
+                    "{input} has no matching {name}.out",
+                    input = input.display(),
+                )));
+            }
+
+            let test = test_name(name);
+            let (input, output) = (input.display().to_string(), output.display().to_string());
+            Ok(quote! {
+                #[test]
+                fn #test() {
+                    run(include_str!(#input), include_str!(#output));
+                }
+            })
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    Ok(quote! {
+        mod samples {
+            ::kickstart_macros::fn_run!(#package #(, #comparison)*);
+
+            #(#tests)*
+        }
+    })
+}
+
+/// Turns a file name into the name of a test function.
+fn test_name(name: &str) -> Ident {
+    let name: String = name
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    if name.starts_with(|c: char| c.is_ascii_digit()) {
+        format_ident!("sample_{name}")
+    } else {
+        format_ident!("{name}")
+    }
+}
@@ -0,0 +1,134 @@
+use std::collections::BTreeMap;
+
+use crate::{compare, Comparison};
+
+/// Whether the output of a program is correct.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CheckResult {
+    Accepted,
+    /// The output is wrong, for the given reason.
+    WrongAnswer(String),
+}
+
+impl CheckResult {
+    /// Returns `true` if the output is accepted.
+    #[must_use]
+    pub const fn is_accepted(&self) -> bool {
+        matches!(self, Self::Accepted)
+    }
+}
+
+/// Decides whether the output of a program is correct.
+///
+/// Problems whose answer is unique can simply compare the output with the reference output,
+/// which a [`Comparison`] does. A problem that allows many correct answers needs a checker
+/// that looks at the input instead, often with a [`Verifier`].
+pub trait Checker {
+    /// Checks `output`, the output of a program given `input`,
+    /// where `reference` is the output of a correct program.
+    fn check(&self, input: &str, output: &str, reference: &str) -> CheckResult;
+}
+
+impl<C: Checker + ?Sized> Checker for &C {
+    fn check(&self, input: &str, output: &str, reference: &str) -> CheckResult {
+        (**self).check(input, output, reference)
+    }
+}
+
+/// The output must match the reference output, ignoring the input.
+impl Checker for Comparison {
+    fn check(&self, _input: &str, output: &str, reference: &str) -> CheckResult {
+        match compare(reference, output, *self) {
+            Ok(()) => CheckResult::Accepted,
+            Err(mismatch) => CheckResult::WrongAnswer(mismatch.to_string()),
+        }
+    }
+}
+
+/// The output must consist of the same whitespace-separated tokens as the reference output,
+/// each appearing the same number of times, but in any order.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct TokenSet;
+
+impl Checker for TokenSet {
+    fn check(&self, _input: &str, output: &str, reference: &str) -> CheckResult {
+        let count = |text| {
+            let mut counts = BTreeMap::new();
+            for token in str::split_ascii_whitespace(text) {
+                *counts.entry(token).or_insert(0) += 1;
+            }
+            counts
+        };
+        let (expected, found) = (count(reference), count(output));
+        let times = |counts: &BTreeMap<&str, usize>, token| counts.get(token).copied().unwrap_or(0);
+
+        // Report the first token, in sorted order, that appears a different number of times.
+        let difference = expected
+            .keys()
+            .chain(found.keys())
+            .filter(|&&token| times(&expected, token) != times(&found, token))
+            .min();
+
+        difference.map_or(CheckResult::Accepted, |&token| {
+            CheckResult::WrongAnswer(format!(
+                "expected `{escaped}` {expected} time(s), but found it {found} time(s)",
+                escaped = token.escape_debug(),
+                expected = times(&expected, token),
+                found = times(&found, token),
+            ))
+        })
+    }
+}
+
+/// A checker that calls a function with the input, the output and the reference output,
+/// which returns the reason if the output is wrong.
+///
+/// ```
+/// use kickstart_macros::{CheckResult, Checker, Verifier};
+///
+/// // Any two numbers that add up to the input are correct.
+/// let checker = Verifier(|input: &str, output: &str, _reference: &str| {
+///     let sum: u32 = input.trim().parse().unwrap();
+///     let numbers: Vec<u32> = output
+///         .split_ascii_whitespace()
+///         .map(|n| n.parse().map_err(|_| format!("`{n}` is not a number")))
+///         .collect::<Result<_, _>>()?;
+///     match numbers[..] {
+///         [a, b] if a + b == sum => Ok(()),
+///         _ => Err(format!("the numbers don't add up to {sum}")),
+///     }
+/// });
+///
+/// assert_eq!(checker.check("5\n", "2 3\n", "1 4\n"), CheckResult::Accepted);
+/// assert!(!checker.check("5\n", "2 2\n", "1 4\n").is_accepted());
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Verifier<F>(pub F);
+
+impl<F: Fn(&str, &str, &str) -> Result<(), String>> Checker for Verifier<F> {
+    fn check(&self, input: &str, output: &str, reference: &str) -> CheckResult {
+        match (self.0)(input, output, reference) {
+            Ok(()) => CheckResult::Accepted,
+            Err(reason) => CheckResult::WrongAnswer(reason),
+        }
+    }
+}
+
+/// Checks the output of a program with a [`Checker`], panicking if it is wrong.
+///
+/// # Panics
+///
+/// Panics with the reason given by the checker if the output is wrong.
+#[track_caller]
+pub fn assert_checked(
+    checker: &(impl Checker + ?Sized),
+    input: &str,
+    output: &[u8],
+    reference: &str,
+) {
+    if let CheckResult::WrongAnswer(reason) =
+        checker.check(input, &String::from_utf8_lossy(output), reference)
+    {
+        panic!("{reason}");
+    }
+}
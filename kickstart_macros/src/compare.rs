@@ -0,0 +1,252 @@
+use std::fmt;
+
+/// How the output of a program is compared with the expected output.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Comparison {
+    /// The output must be exactly the same, byte by byte.
+    Exact,
+    /// The output must consist of the same whitespace-separated tokens;
+    /// the whitespace between them doesn't matter.
+    Tokens,
+    /// The output must consist of the same lines,
+    /// ignoring trailing whitespace (including a `\r`) on each line and empty lines at the end.
+    Lines,
+    /// Like [`Comparison::Tokens`], except that a token that is a number
+    /// matches any number within an absolute error of `abs` or a relative error of `rel`.
+    Float { abs: f64, rel: f64 },
+}
+
+/// The first difference between the output of a program and the expected output.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Mismatch {
+    /// The line number in the output, starting from 1.
+    pub line: usize,
+    /// The column number in the output, counted in bytes and starting from 1.
+    pub column: usize,
+    /// What was expected there, or `None` if the output should have ended.
+    pub expected: Option<String>,
+    /// What was found there, or `None` if the output ended too early.
+    pub found: Option<String>,
+    /// The lines around the difference, in both outputs.
+    pub diff: String,
+}
+
+impl fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let describe = |text: &Option<String>| {
+            text.as_ref().map_or_else(
+                || "the end of the output".into(),
+                |text| format!("`{text}`"),
+            )
+        };
+        writeln!(
+            f,
+            "output mismatch at line {line}, column {column}:",
+            line = self.line,
+            column = self.column,
+        )?;
+        writeln!(f, "  expected {}", describe(&self.expected))?;
+        writeln!(f, "     found {}", describe(&self.found))?;
+        write!(f, "\n{}\n", self.diff)
+    }
+}
+
+/// Compares the output of a program with the expected output.
+///
+/// # Errors
+///
+/// Returns the first difference if the outputs don't match.
+pub fn compare(expected: &str, actual: &str, comparison: Comparison) -> Result<(), Mismatch> {
+    match comparison {
+        Comparison::Exact => compare_exact(expected, actual),
+        Comparison::Tokens => compare_tokens(expected, actual, |expected, found| expected == found),
+        Comparison::Lines => compare_lines(expected, actual),
+        Comparison::Float { abs, rel } => compare_tokens(expected, actual, |expected, found| {
+            expected == found || floats_match(expected, found, abs, rel)
+        }),
+    }
+}
+
+/// Compares the output of a program with the expected output, panicking on any difference.
+///
+/// # Panics
+///
+/// Panics with a readable description of the first difference if the outputs don't match.
+#[track_caller]
+pub fn assert_output(expected: &str, actual: &[u8], comparison: Comparison) {
+    if let Err(mismatch) = compare(expected, &String::from_utf8_lossy(actual), comparison) {
+        panic!("{mismatch}");
+    }
+}
+
+fn compare_exact(expected: &str, actual: &str) -> Result<(), Mismatch> {
+    let Some(index) = first_difference(expected.as_bytes(), actual.as_bytes()) else {
+        return Ok(());
+    };
+
+    let (line, column) = line_column(actual, index);
+    // Show the rest of the line, including the newline, as that may be what differs.
+    let rest_of_line = |text: &str| {
+        let rest = &text.as_bytes()[index.min(text.len())..];
+        let end = rest
+            .iter()
+            .position(|&byte| byte == b'\n')
+            .map_or(rest.len(), |i| i + 1);
+        (!rest.is_empty()).then(|| {
+            String::from_utf8_lossy(&rest[..end])
+                .escape_debug()
+                .to_string()
+        })
+    };
+    Err(Mismatch {
+        line,
+        column,
+        expected: rest_of_line(expected),
+        found: rest_of_line(actual),
+        diff: diff(expected, actual, line),
+    })
+}
+
+fn compare_lines(expected: &str, actual: &str) -> Result<(), Mismatch> {
+    let expected_lines = significant_lines(expected);
+    let actual_lines = significant_lines(actual);
+
+    for line in 0..expected_lines.len().max(actual_lines.len()) {
+        let (expected_line, actual_line) = (expected_lines.get(line), actual_lines.get(line));
+        if expected_line == actual_line {
+            continue;
+        }
+
+        let column = match (expected_line, actual_line) {
+            (Some(expected_line), Some(actual_line)) => {
+                first_difference(expected_line.as_bytes(), actual_line.as_bytes()).unwrap_or(0)
+            }
+            _ => 0,
+        };
+        return Err(Mismatch {
+            line: line + 1,
+            column: column + 1,
+            expected: expected_line.map(|line| line.escape_debug().to_string()),
+            found: actual_line.map(|line| line.escape_debug().to_string()),
+            diff: diff(expected, actual, line + 1),
+        });
+    }
+
+    Ok(())
+}
+
+fn compare_tokens(
+    expected: &str,
+    actual: &str,
+    matches: impl Fn(&str, &str) -> bool,
+) -> Result<(), Mismatch> {
+    let mut expected_tokens = tokens(expected);
+    let mut actual_tokens = tokens(actual);
+
+    loop {
+        let (expected_token, actual_token) = (expected_tokens.next(), actual_tokens.next());
+        let (line, column) = match (expected_token, actual_token) {
+            (None, None) => return Ok(()),
+            (Some((_, e)), Some((_, f))) if matches(e, f) => continue,
+            (_, Some((index, _))) => line_column(actual, index),
+            // Point to the end of the output.
+            (_, None) => line_column(actual, actual.trim_end().len()),
+        };
+
+        return Err(Mismatch {
+            line,
+            column,
+            expected: expected_token.map(|(_, token)| token.escape_debug().to_string()),
+            found: actual_token.map(|(_, token)| token.escape_debug().to_string()),
+            diff: diff(expected, actual, line),
+        });
+    }
+}
+
+/// Returns whether `expected` and `found` are numbers within an absolute error of `abs`
+/// or a relative error of `rel` of each other.
+///
+/// An infinity only matches the same infinity, and NaN only matches NaN,
+/// however they are spelled, such as `inf` and `Infinity`.
+fn floats_match(expected: &str, found: &str, abs: f64, rel: f64) -> bool {
+    let (Ok(expected), Ok(found)) = (expected.parse::<f64>(), found.parse::<f64>()) else {
+        return false;
+    };
+    if expected.is_nan() {
+        return found.is_nan();
+    }
+    if expected.is_infinite() {
+        return found.is_infinite() && found.is_sign_negative() == expected.is_sign_negative();
+    }
+    // A non-finite `found` gives an infinite or NaN error, which is never within the tolerance.
+    let error = (expected - found).abs();
+    error <= abs || error <= rel * expected.abs()
+}
+
+/// Returns the whitespace-separated tokens in `text`, with their byte indices.
+fn tokens(text: &str) -> impl Iterator<Item = (usize, &str)> {
+    text.split(|c: char| c.is_ascii_whitespace())
+        .filter(|token| !token.is_empty())
+        // The token is a subslice of `text`, so this gives its index.
+        .map(move |token| (token.as_ptr() as usize - text.as_ptr() as usize, token))
+}
+
+/// Returns the lines of `text` without trailing whitespace, and without empty lines at the end.
+fn significant_lines(text: &str) -> Vec<&str> {
+    let mut lines: Vec<&str> = text.split('\n').map(str::trim_end).collect();
+    while lines.last().is_some_and(|line| line.is_empty()) {
+        lines.pop();
+    }
+    lines
+}
+
+/// Returns the index of the first byte that differs, or `None` if the bytes are the same.
+fn first_difference(expected: &[u8], actual: &[u8]) -> Option<usize> {
+    let common = std::iter::zip(expected, actual)
+        .take_while(|(e, a)| e == a)
+        .count();
+    (common != expected.len() || common != actual.len()).then_some(common)
+}
+
+/// Returns the line and column numbers, starting from 1, of the byte at `index`.
+fn line_column(text: &str, index: usize) -> (usize, usize) {
+    let before = &text.as_bytes()[..index.min(text.len())];
+    let line_start = before
+        .iter()
+        .rposition(|&byte| byte == b'\n')
+        .map_or(0, |i| i + 1);
+    let line = before.split(|&byte| byte == b'\n').count();
+    (line, before.len() - line_start + 1)
+}
+
+/// Shows the lines around `line` in both outputs:
+/// lines that only appear in the expected output are marked with `-`,
+/// and those that only appear in the actual output are marked with `+`.
+fn diff(expected: &str, actual: &str, line: usize) -> String {
+    const CONTEXT: usize = 2;
+
+    let expected_lines: Vec<&str> = expected.split_terminator('\n').collect();
+    let actual_lines: Vec<&str> = actual.split_terminator('\n').collect();
+
+    let first = line.saturating_sub(CONTEXT).max(1);
+    let last = (line + CONTEXT).min(expected_lines.len().max(actual_lines.len()));
+
+    let mut diff = Vec::new();
+    for number in first..=last {
+        let (expected_line, actual_line) =
+            (expected_lines.get(number - 1), actual_lines.get(number - 1));
+        if expected_line == actual_line {
+            if let Some(line) = expected_line {
+                diff.push(format!("  {number:>4} | {}", line.escape_debug()));
+            }
+            continue;
+        }
+        if let Some(line) = expected_line {
+            diff.push(format!("- {number:>4} | {}", line.escape_debug()));
+        }
+        if let Some(line) = actual_line {
+            diff.push(format!("+ {number:>4} | {}", line.escape_debug()));
+        }
+    }
+    diff.join("\n")
+}
@@ -1,10 +1,27 @@
+mod checker;
+mod compare;
+
+pub use checker::{assert_checked, CheckResult, Checker, TokenSet, Verifier};
+pub use compare::{assert_output, compare, Comparison, Mismatch};
+
 /// Generates a function that tests the program by calling `main_impl`.
 /// ```ignore
 /// fn run(input: &str, output: &'static str);
 /// ```
+///
+/// The output must match exactly, unless another [`Comparison`] is given:
+/// ```ignore
+/// fn_run!(a2, kickstart_macros::Comparison::Lines);
+/// ```
+///
+/// Any other [`Checker`] may be given instead, for problems with more than one correct output;
+/// `output` is then the output of a correct program.
 #[macro_export]
 macro_rules! fn_run {
     ($package:ident) => {
+        $crate::fn_run!($package, $crate::Comparison::Exact);
+    };
+    ($package:ident, $checker:expr) => {
         /// Runs the `main_impl` function and pass `input` as a parameter,
         /// then ensures that it produces `output` in the writer.
         #[track_caller]
@@ -14,18 +31,62 @@ macro_rules! fn_run {
 
             $package::main_impl(input.as_bytes(), &mut output_writer);
 
-            assert_eq!(&*output_writer, output.as_bytes());
+            $crate::assert_checked(&$checker, input, &output_writer, output);
         }
     };
 }
 
+/// Generates a test for each pair of `NAME.in` and `NAME.out` files in a directory,
+/// which checks that `main_impl` produces exactly `NAME.out` given `NAME.in`.
+/// ```ignore
+/// sample_tests!(b5, "tests/samples");
+/// ```
+///
+/// The directory is relative to the package.
+/// The tests are put in a module named `samples`, along with a `run` function from [`fn_run!`];
+/// a [`Comparison`] or another [`Checker`] may be given as a third argument, like for [`fn_run!`].
+///
+/// Cargo doesn't notice new files in the directory by itself,
+/// so the package should have a build script that tells it to:
+/// ```ignore
+/// println!("cargo:rerun-if-changed=tests/samples");
+/// ```
+pub use kickstart_derive::sample_tests;
+// Used by `fn_main!`, so that packages need not depend on them.
+#[doc(hidden)]
+pub use read_u32;
+#[doc(hidden)]
+pub use write_int;
+
 /// Generates a `main` function that calls `main_impl` with standard input and output.
+///
+/// Standard output is block-buffered with an [`IntWriter`](write_int::IntWriter).
+///
+/// With `slurp`, the entire standard input is read into memory before calling `main_impl`,
+/// so that it is parsed as a [`SliceReader`](read_u32::SliceReader).
+/// ```ignore
+/// fn_main!(a6, slurp);
+/// ```
 #[macro_export]
 macro_rules! fn_main {
     ($package:ident) => {
         /// Entry point.
         fn main() {
-            $package::main_impl(std::io::stdin().lock(), std::io::stdout().lock());
+            $package::main_impl(
+                std::io::stdin().lock(),
+                $crate::write_int::IntWriter::new(std::io::stdout().lock()),
+            );
+        }
+    };
+    ($package:ident, slurp) => {
+        /// Entry point.
+        fn main() {
+            let input =
+                $crate::read_u32::slurp(std::io::stdin().lock()).expect("An input error occured");
+            $package::main_impl(
+                input.as_slice(),
+                $crate::write_int::IntWriter::new(std::io::stdout().lock()),
+            );
         }
     };
 }
@@ -0,0 +1,72 @@
+use kickstart_macros::{assert_checked, CheckResult, Checker, Comparison, TokenSet, Verifier};
+
+#[test]
+fn comparison() {
+    assert!(Comparison::Lines.check("", "1 \n2\n", "1\n2").is_accepted());
+
+    let CheckResult::WrongAnswer(reason) = Comparison::Exact.check("", "1\n", "2\n") else {
+        panic!("accepted a wrong answer");
+    };
+    assert!(reason.starts_with("output mismatch at line 1, column 1"));
+}
+
+#[test]
+fn float() {
+    let checker = Comparison::Float {
+        abs: 1e-6,
+        rel: 1e-6,
+    };
+    assert!(checker
+        .check("", "0.3333333\n", "0.333333333\n")
+        .is_accepted());
+    assert!(!checker.check("", "0.33\n", "0.333333333\n").is_accepted());
+}
+
+#[test]
+fn token_set() {
+    assert!(TokenSet.check("", "3 1\n2\n", "1 2 3\n").is_accepted());
+    assert_eq!(
+        TokenSet.check("", "1 2 2\n", "1 2 3\n"),
+        CheckResult::WrongAnswer("expected `2` 1 time(s), but found it 2 time(s)".into()),
+    );
+    assert_eq!(
+        TokenSet.check("", "1\n", "1 1\n"),
+        CheckResult::WrongAnswer("expected `1` 2 time(s), but found it 1 time(s)".into()),
+    );
+}
+
+#[test]
+fn verifier() {
+    // The output must be a divisor of the input other than 1 and itself.
+    let checker = Verifier(|input: &str, output: &str, reference: &str| {
+        let n: u32 = input.trim().parse().unwrap();
+        let d: u32 = output
+            .trim()
+            .parse()
+            .map_err(|_| "not a number".to_owned())?;
+        if reference.trim() == "-1" {
+            return Err("there is no such divisor".into());
+        }
+        if d == 1 || d == n || !n.is_multiple_of(d) {
+            return Err(format!("{d} is not a proper divisor of {n}"));
+        }
+        Ok(())
+    });
+
+    assert!(checker.check("12\n", "3\n", "2\n").is_accepted());
+    assert!(checker.check("12\n", "6\n", "2\n").is_accepted());
+    assert_eq!(
+        checker.check("12\n", "5\n", "2\n"),
+        CheckResult::WrongAnswer("5 is not a proper divisor of 12".into()),
+    );
+    assert_eq!(
+        checker.check("12\n", "five\n", "2\n"),
+        CheckResult::WrongAnswer("not a number".into()),
+    );
+}
+
+#[test]
+#[should_panic = "expected `3` 1 time(s), but found it 0 time(s)"]
+fn assert_checked_panics() {
+    assert_checked(&TokenSet, "", b"1 2\n", "1 2 3\n");
+}
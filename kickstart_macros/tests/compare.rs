@@ -0,0 +1,121 @@
+use kickstart_macros::{compare, Comparison, Mismatch};
+
+#[test]
+fn exact() {
+    assert_eq!(compare("1\n2\n", "1\n2\n", Comparison::Exact), Ok(()));
+
+    let mismatch = compare("1\n2\n", "1\r\n2\n", Comparison::Exact).unwrap_err();
+    assert_eq!((mismatch.line, mismatch.column), (1, 2));
+    assert_eq!(mismatch.expected.as_deref(), Some("\\n"));
+    assert_eq!(mismatch.found.as_deref(), Some("\\r\\n"));
+
+    // Missing final newline.
+    let mismatch = compare("1\n", "1", Comparison::Exact).unwrap_err();
+    assert_eq!(mismatch.found, None);
+}
+
+#[test]
+fn tokens() {
+    assert_eq!(compare("1 2\n3\n", "1\n2   3", Comparison::Tokens), Ok(()));
+
+    let mismatch = compare("1 2\n3 4\n", "1 2\n3  5\n", Comparison::Tokens).unwrap_err();
+    assert_eq!((mismatch.line, mismatch.column), (2, 4));
+    assert_eq!(mismatch.expected.as_deref(), Some("4"));
+    assert_eq!(mismatch.found.as_deref(), Some("5"));
+
+    let mismatch = compare("1 2\n", "1\n", Comparison::Tokens).unwrap_err();
+    assert_eq!(mismatch.expected.as_deref(), Some("2"));
+    assert_eq!(mismatch.found, None);
+}
+
+#[test]
+fn lines() {
+    assert_eq!(
+        compare("YES 3\nNO 1\n", "YES 3  \r\nNO 1\n\n", Comparison::Lines),
+        Ok(())
+    );
+
+    // Whitespace within a line still matters.
+    let mismatch = compare("YES 3\n", "YES  3\n", Comparison::Lines).unwrap_err();
+    assert_eq!((mismatch.line, mismatch.column), (1, 5));
+}
+
+#[test]
+fn float() {
+    let comparison = Comparison::Float {
+        abs: 1e-6,
+        rel: 1e-9,
+    };
+    assert_eq!(compare("0.1 YES\n", "0.1000001 YES", comparison), Ok(()));
+    assert_eq!(compare("1e12\n", "1000000000000.5\n", comparison), Ok(()));
+    assert!(compare("0.1\n", "0.11\n", comparison).is_err());
+    assert!(compare("YES\n", "NO\n", comparison).is_err());
+}
+
+#[test]
+fn float_non_finite() {
+    let comparison = Comparison::Float {
+        abs: 1e-6,
+        rel: 1e-9,
+    };
+    assert_eq!(
+        compare("inf -inf NaN\n", "inf -inf NaN\n", comparison),
+        Ok(())
+    );
+    assert_eq!(compare("inf NaN\n", "Infinity nan\n", comparison), Ok(()));
+    assert!(compare("inf\n", "-inf\n", comparison).is_err());
+    assert!(compare("inf\n", "1e308\n", comparison).is_err());
+    assert!(compare("NaN\n", "0\n", comparison).is_err());
+    // A finite answer is never matched by a non-finite one.
+    assert!(compare("1\n", "inf\n", comparison).is_err());
+    assert!(compare("1\n", "NaN\n", comparison).is_err());
+}
+
+#[test]
+fn readable_message() {
+    let mismatch: Mismatch =
+        compare("1\n2\n3\n4\n", "1\n2\n5\n4\n", Comparison::Exact).unwrap_err();
+    assert_eq!(
+        mismatch.to_string(),
+        "\
+output mismatch at line 3, column 1:
+  expected `3\\n`
+     found `5\\n`
+
+     1 | 1
+     2 | 2
+-    3 | 3
++    3 | 5
+     4 | 4
+",
+    );
+}
+
+mod fn_run_lines {
+    kickstart_macros::fn_run!(b1_like, kickstart_macros::Comparison::Lines);
+
+    // A stand-in for a package with trailing spaces in its output.
+    mod b1_like {
+        pub fn main_impl(_input: impl std::io::BufRead, mut output: impl std::io::Write) {
+            output.write_all(b"YES 3 \r\n").unwrap();
+        }
+    }
+
+    #[test]
+    fn trailing_whitespace() {
+        run("", "YES 3\n");
+    }
+}
+
+#[test]
+#[should_panic(expected = "output mismatch at line 1, column 4")]
+fn fn_run_exact() {
+    mod b2_like {
+        pub fn main_impl(_input: impl std::io::BufRead, mut output: impl std::io::Write) {
+            output.write_all(b"YES \n").unwrap();
+        }
+    }
+    kickstart_macros::fn_run!(b2_like);
+
+    run("", "YES\n");
+}
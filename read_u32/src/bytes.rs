@@ -0,0 +1,232 @@
+use std::{
+    io::{self, prelude::*},
+    mem,
+};
+
+use crate::{fill_buf, invalid_byte, Delimiter, Mode, ReadError, U32Reader};
+
+/// A reader that can put off consuming the bytes at the start of its buffer.
+///
+/// This allows a [`U32Reader`] to return slices of the buffer of the underlying reader;
+/// the bytes in the slice are only consumed once the reader is used again.
+#[derive(Clone, Debug)]
+pub struct DeferredConsume<R> {
+    pub inner: R,
+    deferred: usize,
+}
+
+impl<R: BufRead> DeferredConsume<R> {
+    pub const fn new(inner: R) -> Self {
+        Self { inner, deferred: 0 }
+    }
+
+    /// Consumes the bytes whose consumption was put off.
+    pub fn commit(&mut self) {
+        self.inner.consume(mem::take(&mut self.deferred));
+    }
+
+    pub fn into_inner(mut self) -> R {
+        self.commit();
+        self.inner
+    }
+}
+
+impl<R: BufRead> Read for DeferredConsume<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.commit();
+        self.inner.read(buf)
+    }
+}
+
+impl<R: BufRead> BufRead for DeferredConsume<R> {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        self.commit();
+        self.inner.fill_buf()
+    }
+
+    fn consume(&mut self, amt: usize) {
+        self.commit();
+        self.inner.consume(amt);
+    }
+}
+
+impl<R: BufRead> U32Reader<R> {
+    /// Reads a non-empty run of non-whitespace bytes followed by the given delimiter.
+    ///
+    /// If both the bytes and the delimiter are in the buffer of the underlying reader,
+    /// a slice of that buffer is returned without copying.
+    fn try_read_bytes_until(&mut self, delim: Delimiter) -> Result<&[u8], ReadError> {
+        let complete = complete_token(fill_buf(&mut self.reader, self.position)?, delim, self.mode);
+
+        if let Some((len, delim_len)) = complete {
+            // Nothing is deferred here, as `fill_buf` has just committed it.
+            let available = fill_buf(&mut self.reader.inner, self.position)?;
+            self.position.advance(&available[..len + delim_len]);
+            self.reader.deferred = len + delim_len;
+            return Ok(&available[..len]);
+        }
+
+        // The bytes may be split between buffers, or there may be some error.
+        self.try_copy_token()?;
+        if self.buf.is_empty() {
+            let position = self.position;
+            return Err(self
+                .peek_byte()?
+                .map_or(ReadError::UnexpectedEof { position }, |found| {
+                    invalid_byte(found, position)
+                }));
+        }
+        self.try_consume_delimiter(delim)?;
+        Ok(&self.buf)
+    }
+
+    /// Reads a token, which is a non-empty run of non-whitespace bytes,
+    /// terminated by a space character (`' '`), a newline (`\n` or `\r\n`) or the end of the input.
+    ///
+    /// In [`Mode::Lenient`], any whitespace may surround the token instead.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the token is empty,
+    /// if it is not followed by a space, a newline or the end of the input,
+    /// or if any I/O error occured.
+    pub fn try_read_token(&mut self) -> Result<&[u8], ReadError> {
+        if self.mode == Mode::Lenient {
+            self.try_skip_whitespace()?;
+        }
+        self.try_read_bytes_until(Delimiter::Any)
+    }
+
+    /// Reads the rest of the current line, without the newline (`\n` or `\r\n`) that terminates it.
+    ///
+    /// The last line of the input need not end with a newline.
+    /// The line is returned as is, even in [`Mode::Lenient`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the end of the input has already been reached,
+    /// or if any I/O error occured.
+    pub fn try_read_line_bytes(&mut self) -> Result<&[u8], ReadError> {
+        let newline = memchr::memchr(b'\n', fill_buf(&mut self.reader, self.position)?);
+
+        if let Some(newline) = newline {
+            let available = fill_buf(&mut self.reader.inner, self.position)?;
+            self.position.advance(&available[..=newline]);
+            self.reader.deferred = newline + 1;
+            return Ok(strip_cr(&available[..newline]));
+        }
+
+        // The line is split between buffers, or is the last line.
+        self.buf.clear();
+        loop {
+            let position = self.position;
+            let available = fill_buf(&mut self.reader, position)?;
+            if available.is_empty() {
+                // Nothing at all was left to read.
+                if self.buf.is_empty() {
+                    return Err(ReadError::UnexpectedEof { position });
+                }
+                break;
+            }
+
+            let newline = memchr::memchr(b'\n', available);
+            let used = newline.map_or(available.len(), |newline| newline + 1);
+
+            self.buf
+                .extend_from_slice(&available[..newline.unwrap_or(used)]);
+            self.position.advance(&available[..used]);
+            self.reader.consume(used);
+
+            if newline.is_some() {
+                break;
+            }
+        }
+
+        Ok(strip_cr(&self.buf))
+    }
+
+    /// Reads a row of exactly `N` non-whitespace bytes,
+    /// terminated by a newline (`\n` or `\r\n`) or the end of the input.
+    ///
+    /// In [`Mode::Lenient`], any whitespace may surround the row instead.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the row does not contain exactly `N` bytes,
+    /// if it is not followed by a newline or the end of the input,
+    /// or if any I/O error occured.
+    pub fn try_read_fixed_row<const N: usize>(&mut self) -> Result<&[u8; N], ReadError> {
+        if self.mode == Mode::Lenient {
+            self.try_skip_whitespace()?;
+        }
+
+        let position = self.position;
+        let row = self.try_read_bytes_until(Delimiter::Newline)?;
+        row.try_into().map_err(|_| ReadError::WrongLength {
+            expected: N,
+            found: row.len(),
+            position,
+        })
+    }
+
+    /// Reads a token, which is a non-empty run of non-whitespace bytes,
+    /// terminated by a space character (`' '`), a newline (`\n` or `\r\n`) or the end of the input.
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`try_read_token`](Self::try_read_token) returns an error.
+    #[must_use]
+    pub fn read_token(&mut self) -> &[u8] {
+        self.try_read_token()
+            .unwrap_or_else(|error| panic!("Failed to read an input token: {error}"))
+    }
+
+    /// Reads the rest of the current line, without the newline (`\n` or `\r\n`) that terminates it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`try_read_line_bytes`](Self::try_read_line_bytes) returns an error.
+    #[must_use]
+    pub fn read_line_bytes(&mut self) -> &[u8] {
+        self.try_read_line_bytes()
+            .unwrap_or_else(|error| panic!("Failed to read an input line: {error}"))
+    }
+
+    /// Reads a row of exactly `N` non-whitespace bytes,
+    /// terminated by a newline (`\n` or `\r\n`) or the end of the input.
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`try_read_fixed_row`](Self::try_read_fixed_row) returns an error.
+    #[must_use]
+    pub fn read_fixed_row<const N: usize>(&mut self) -> &[u8; N] {
+        self.try_read_fixed_row()
+            .unwrap_or_else(|error| panic!("Failed to read an input row: {error}"))
+    }
+}
+
+/// Returns the lengths of the token at the start of `available` and of the delimiter after it,
+/// if both are complete and valid within `available`.
+fn complete_token(available: &[u8], delim: Delimiter, mode: Mode) -> Option<(usize, usize)> {
+    let len = available.iter().position(u8::is_ascii_whitespace)?;
+    if len == 0 {
+        return None;
+    }
+
+    let delim_len = match (mode, delim, &available[len..]) {
+        // The buffer must show where the whitespace ends.
+        (Mode::Lenient, _, rest) => rest.iter().position(|byte| !byte.is_ascii_whitespace())?,
+        (Mode::Strict, Delimiter::Space | Delimiter::Any, [b' ', ..])
+        | (Mode::Strict, Delimiter::Newline | Delimiter::Any, [b'\n', ..]) => 1,
+        (Mode::Strict, Delimiter::Newline | Delimiter::Any, [b'\r', b'\n', ..]) => 2,
+        // Let the caller report any error.
+        _ => return None,
+    };
+
+    Some((len, delim_len))
+}
+
+/// Removes the `\r` of a `\r\n` newline.
+fn strip_cr(line: &[u8]) -> &[u8] {
+    line.strip_suffix(b"\r").unwrap_or(line)
+}
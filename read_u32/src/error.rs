@@ -0,0 +1,141 @@
+use std::{error, fmt, io};
+
+/// A position in the input.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Position {
+    /// The number of bytes before this position.
+    pub offset: u64,
+    /// The line number, starting from 1.
+    pub line: u64,
+    /// The column number, counted in bytes and starting from 1.
+    pub column: u64,
+}
+
+impl Position {
+    /// The position of the first byte of the input.
+    pub const START: Self = Self {
+        offset: 0,
+        line: 1,
+        column: 1,
+    };
+
+    /// Moves the position past the given bytes.
+    pub(crate) fn advance(&mut self, bytes: &[u8]) {
+        self.offset += bytes.len() as u64;
+        match memchr::memrchr(b'\n', bytes) {
+            // Start counting columns again after the last newline.
+            Some(last_newline) => {
+                self.line += memchr::memchr_iter(b'\n', bytes).count() as u64;
+                self.column = (bytes.len() - last_newline) as u64;
+            }
+            None => self.column += bytes.len() as u64,
+        }
+    }
+
+    /// Returns the position that is `bytes` bytes to the right, on the same line.
+    #[must_use]
+    pub const fn shifted(self, bytes: usize) -> Self {
+        Self {
+            offset: self.offset + bytes as u64,
+            column: self.column + bytes as u64,
+            ..self
+        }
+    }
+}
+
+impl Default for Position {
+    fn default() -> Self {
+        Self::START
+    }
+}
+
+impl fmt::Display for Position {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {line}, column {column} (byte {offset})",
+            line = self.line,
+            column = self.column,
+            offset = self.offset,
+        )
+    }
+}
+
+/// An error that occured when reading a number.
+#[derive(Debug)]
+pub enum ReadError {
+    /// The underlying reader returned an error.
+    Io {
+        error: io::Error,
+        position: Position,
+    },
+    /// The input ended before a complete number (and its delimiter) could be read.
+    UnexpectedEof { position: Position },
+    /// The number cannot be represented by the requested type.
+    Overflow { position: Position },
+    /// A byte that is neither a part of a number nor a delimiter was found.
+    InvalidDigit { position: Position },
+    /// A whitespace byte was found where a different one was expected.
+    UnexpectedDelimiter { found: u8, position: Position },
+    /// A row of bytes does not have the expected length.
+    WrongLength {
+        expected: usize,
+        found: usize,
+        position: Position,
+    },
+}
+
+impl ReadError {
+    /// Returns the position in the input where this error occured.
+    #[must_use]
+    pub const fn position(&self) -> Position {
+        match *self {
+            Self::Io { position, .. }
+            | Self::UnexpectedEof { position }
+            | Self::Overflow { position }
+            | Self::InvalidDigit { position }
+            | Self::UnexpectedDelimiter { position, .. }
+            | Self::WrongLength { position, .. } => position,
+        }
+    }
+}
+
+impl fmt::Display for ReadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io { error, position } => {
+                write!(f, "an input error occured at {position}: {error}")
+            }
+            Self::UnexpectedEof { position } => {
+                write!(f, "unexpected end of input at {position}")
+            }
+            Self::Overflow { position } => write!(
+                f,
+                "the number at {position} cannot be represented by the requested type"
+            ),
+            Self::InvalidDigit { position } => write!(f, "invalid digit at {position}"),
+            Self::UnexpectedDelimiter { found, position } => write!(
+                f,
+                "unexpected delimiter '{found}' at {position}",
+                found = found.escape_ascii(),
+            ),
+            Self::WrongLength {
+                expected,
+                found,
+                position,
+            } => write!(
+                f,
+                "expected a row of {expected} bytes at {position}, found {found} bytes"
+            ),
+        }
+    }
+}
+
+impl error::Error for ReadError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::Io { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
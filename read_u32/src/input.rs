@@ -0,0 +1,60 @@
+use std::io;
+
+use crate::{ReadError, U32Reader};
+
+/// An input layout that can be read from a [`U32Reader`].
+///
+/// This can be derived for structs with named fields, which are read in order.
+/// Each field is either:
+/// - a [`Number`](crate::Number), followed by a space,
+///   or by a newline if it is the last field;
+///   use `#[sep = "space"]`, `#[sep = "newline"]` or `#[line]` (same as `#[sep = "newline"]`)
+///   to choose the delimiter explicitly.
+/// - a `Vec` of inputs with `#[count_from = "n"]`,
+///   where `n` is an earlier field of type `usize`:
+///   `n` inputs of the element type, one after another.
+/// - a `Vec` of numbers with both `#[count_from = "n"]` and `#[line]`:
+///   a row of `n` numbers, separated by spaces, on a line of its own.
+///
+/// Instead of `#[count_from = "n"]`, `#[count_prefixed]` reads the count from a line of its own
+/// just before the `Vec`, for when the count is not needed afterwards.
+///
+/// ```
+/// use read_u32::{KickstartInput, U32Reader};
+///
+/// #[derive(KickstartInput)]
+/// struct Input {
+///     #[count_prefixed]
+///     test_cases: Vec<TestCase>,
+/// }
+///
+/// #[derive(KickstartInput)]
+/// struct TestCase {
+///     #[sep = "space"]
+///     price: u32,
+///     #[line]
+///     wallet: u32,
+/// }
+///
+/// let input = Input::read_from(&mut U32Reader::new(&b"2\n1 3\n1 11\n"[..]));
+/// assert_eq!(input.test_cases[1].wallet, 11);
+/// ```
+pub trait KickstartInput: Sized {
+    /// Reads the input from the given reader.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the input doesn't follow the layout,
+    /// as reported by the methods of [`U32Reader`].
+    fn try_read_from<R: io::BufRead>(reader: &mut U32Reader<R>) -> Result<Self, ReadError>;
+
+    /// Reads the input from the given reader.
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`try_read_from`](Self::try_read_from) returns an error.
+    fn read_from<R: io::BufRead>(reader: &mut U32Reader<R>) -> Self {
+        Self::try_read_from(reader)
+            .unwrap_or_else(|error| panic!("Failed to read the input: {error}"))
+    }
+}
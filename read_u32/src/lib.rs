@@ -1,124 +1,578 @@
-use std::io;
+use std::io::{self, prelude::*};
 
-/// An adapter over a reader that reads `u32`s from it.
+use bytes::DeferredConsume;
+
+mod bytes;
+mod error;
+mod input;
+mod validate;
+
+pub use error::{Position, ReadError};
+pub use input::KickstartInput;
+pub use kickstart_derive::KickstartInput;
+pub use validate::{ValidationError, Validator};
+
+/// A number that can be read by a [`U32Reader`].
+///
+/// This is implemented for all primitive integer and floating-point types.
+pub trait Number: lexical::FromLexical {}
+
+macro_rules! impl_number {
+    ($($ty:ty)*) => {
+        $(impl Number for $ty {})*
+    };
+}
+
+impl_number! {
+    u8 u16 u32 u64 u128 usize
+    i8 i16 i32 i64 i128 isize
+    f32 f64
+}
+
+/// How a [`U32Reader`] treats the whitespace between numbers.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Mode {
+    /// Numbers must be separated by exactly the delimiter the caller asks for:
+    /// a single space, or a single newline (`\n` or `\r\n`).
+    /// Only spaces or tabs at the end of a line are let through.
+    #[default]
+    Strict,
+    /// Numbers may be separated by any run of ASCII whitespace, including none before the first number.
+    /// Whether the caller asks for a space or a newline is ignored,
+    /// so numbers may also be wrapped across lines,
+    /// but a row of numbers must still end with a line.
+    Lenient,
+}
+
+impl Mode {
+    /// The environment variable that, when set, makes [`Mode::from_env`] return [`Mode::Lenient`].
+    pub const ENV_VAR: &'static str = "KICKSTART_LENIENT";
+
+    /// Returns [`Mode::Lenient`] if the `KICKSTART_LENIENT` environment variable is set,
+    /// or [`Mode::Strict`] otherwise.
+    ///
+    /// This allows the same binary to run against messy test data.
+    #[must_use]
+    pub fn from_env() -> Self {
+        if std::env::var_os(Self::ENV_VAR).is_some() {
+            Self::Lenient
+        } else {
+            Self::Strict
+        }
+    }
+}
+
+/// The delimiter that is expected to follow a number.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Delimiter {
+    /// A single space character (`' '`).
+    Space,
+    /// A newline (`\n` or `\r\n`), or the end of the input.
+    Newline,
+    /// Either of the above.
+    Any,
+    /// The end of a row, which is a [`Delimiter::Newline`],
+    /// but which in [`Mode::Lenient`] must still be followed by a line break or the end of the input.
+    RowEnd,
+}
+
+/// An adapter over a reader that reads numbers from it.
+///
+/// Despite the name, any [`Number`] can be read, not just `u32`s.
+///
+/// The adapter keeps track of its [`Position`] in the input,
+/// which is reported in any [`ReadError`].
 #[derive(Clone, Debug)]
 pub struct U32Reader<R: io::BufRead> {
     buf: Vec<u8>,
-    reader: R,
+    reader: DeferredConsume<R>,
+    position: Position,
+    mode: Mode,
 }
 
 impl<R: io::BufRead> U32Reader<R> {
-    /// Creates a new adapter that reads `u32`s from the given reader.
+    /// Creates a new adapter that reads numbers from the given reader in [`Mode::Strict`].
     #[must_use]
     pub const fn new(reader: R) -> Self {
+        Self::with_mode(reader, Mode::Strict)
+    }
+
+    /// Creates a new adapter that reads numbers from the given reader in the given mode.
+    #[must_use]
+    pub const fn with_mode(reader: R, mode: Mode) -> Self {
         Self {
             buf: Vec::new(),
-            reader,
+            reader: DeferredConsume::new(reader),
+            position: Position::START,
+            mode,
         }
     }
 
     /// Returns a mutable reference to the underlying reader.
+    ///
+    /// Any bytes consumed directly from the underlying reader
+    /// are not accounted for in the [`position`](Self::position) of this adapter.
     pub fn get_mut(&mut self) -> &mut R {
-        &mut self.reader
+        self.reader.commit();
+        &mut self.reader.inner
     }
 
     /// Unwraps the adapter, returning the underlying reader.
-    // This clippy lint is a false positive; compiler complains
-    // "destructors cannot be evaluated at compile-time" if `const` is added.
-    // This is because we have to drop the buffer.
-    #[allow(clippy::missing_const_for_fn)]
     pub fn into_inner(self) -> R {
-        self.reader
+        self.reader.into_inner()
+    }
+
+    /// Returns the position of the next byte to be read.
+    #[must_use]
+    pub const fn position(&self) -> Position {
+        self.position
+    }
+
+    /// Returns how this adapter treats the whitespace between numbers.
+    #[must_use]
+    pub const fn mode(&self) -> Mode {
+        self.mode
+    }
+
+    /// Returns the next byte of the underlying reader without consuming it,
+    /// or `None` if the end of the input has been reached.
+    fn peek_byte(&mut self) -> Result<Option<u8>, ReadError> {
+        fill_buf(&mut self.reader, self.position).map(|available| available.first().copied())
     }
 
-    /// Reads a `u32` from the underlying reader in a zero-copy manner.
+    /// Consumes a byte previously returned by `peek_byte`.
+    fn consume_byte(&mut self, byte: u8) {
+        self.reader.consume(1);
+        self.position.advance(&[byte]);
+    }
+
+    /// Consumes all ASCII whitespace until the next non-whitespace byte or the end of the input.
+    fn try_skip_whitespace(&mut self) -> Result<(), ReadError> {
+        loop {
+            let available = fill_buf(&mut self.reader, self.position)?;
+
+            let whitespace = available
+                .iter()
+                .position(|byte| !byte.is_ascii_whitespace())
+                .unwrap_or(available.len());
+            // Stop if we've found a non-whitespace byte, or the end of the input.
+            let done = whitespace < available.len() || available.is_empty();
+
+            self.position.advance(&available[..whitespace]);
+            self.reader.consume(whitespace);
+
+            if done {
+                return Ok(());
+            }
+        }
+    }
+
+    /// Consumes the delimiter that follows a number.
     ///
-    /// Use only when the delimiter is guaranteed to be a single byte,
-    /// meaning that this is unsuitable for reading integers terminated by a newline,
-    /// because that can end with either `\n` or `\r\n`.
+    /// In [`Mode::Lenient`], this consumes any whitespace regardless of `delim`.
+    fn try_consume_delimiter(&mut self, delim: Delimiter) -> Result<(), ReadError> {
+        if self.mode == Mode::Lenient {
+            let (position, found) = (self.position, self.peek_byte()?);
+            self.try_skip_whitespace()?;
+            // Any whitespace will do between numbers, but another number on the same line
+            // means that the row has more numbers than were asked for.
+            return match found {
+                Some(found)
+                    if delim == Delimiter::RowEnd
+                        && self.position.line == position.line
+                        && self.peek_byte()?.is_some() =>
+                {
+                    Err(ReadError::UnexpectedDelimiter { found, position })
+                }
+                _ => Ok(()),
+            };
+        }
+
+        // Spaces or tabs at the end of a line are tolerated, as hand-written inputs often have them.
+        if matches!(delim, Delimiter::Newline | Delimiter::RowEnd) {
+            let position = self.position;
+            let mut blank = None;
+            while let Some(byte @ (b' ' | b'\t')) = self.peek_byte()? {
+                blank = blank.or(Some(byte));
+                self.consume_byte(byte);
+            }
+            // But if the line goes on, the first blank is the unexpected delimiter.
+            if let Some(found) = blank {
+                if !matches!(self.peek_byte()?, None | Some(b'\n' | b'\r')) {
+                    return Err(ReadError::UnexpectedDelimiter { found, position });
+                }
+            }
+        }
+
+        let position = self.position;
+
+        match (delim, self.peek_byte()?) {
+            (Delimiter::Space | Delimiter::Any, Some(byte @ b' '))
+            | (Delimiter::Newline | Delimiter::RowEnd | Delimiter::Any, Some(byte @ b'\n')) => {
+                self.consume_byte(byte);
+                Ok(())
+            }
+            // The last line might not end with a newline.
+            (Delimiter::Newline | Delimiter::RowEnd | Delimiter::Any, None) => Ok(()),
+            // Windows-style newline.
+            (Delimiter::Newline | Delimiter::RowEnd | Delimiter::Any, Some(b'\r')) => {
+                self.consume_byte(b'\r');
+                match self.peek_byte()? {
+                    Some(b'\n') => {
+                        self.consume_byte(b'\n');
+                        Ok(())
+                    }
+                    // Report the lone `\r` as the unexpected delimiter.
+                    _ => Err(ReadError::UnexpectedDelimiter {
+                        found: b'\r',
+                        position,
+                    }),
+                }
+            }
+            (Delimiter::Space, None) => Err(ReadError::UnexpectedEof { position }),
+            (_, Some(found)) => Err(invalid_byte(found, position)),
+        }
+    }
+
+    /// Reads a number from the underlying reader in a zero-copy manner.
     ///
-    /// May return None if the underlying buffer of the reader does not contain any delimiter.
-    /// One may use `read_until_fallback` in that case.
+    /// The number is parsed directly from the internal buffer of the underlying reader.
+    /// The delimiter is then consumed separately,
+    /// so a `\r\n` newline may span two buffers of the underlying reader.
     ///
-    /// Panics if no valid digits can be obtained from the underlying reader,
-    /// if the integer cannot fit into a `u32`,
-    /// or if any I/O error occured.
-    #[must_use]
-    fn read_until_fast(&mut self) -> Option<u32> {
+    /// May return `Ok(None)` if the underlying buffer of the reader ends before the number does,
+    /// so that the number might be split between two buffers.
+    /// One may use `try_read_until_fallback` in that case.
+    fn try_read_until_fast<T: Number>(&mut self, delim: Delimiter) -> Result<Option<T>, ReadError> {
+        let position = self.position;
+
+        // Get the internal buffer of the underying reader.
+        let available = fill_buf(&mut self.reader, position)?;
+
+        // Parse the first bytes of the buffer into a number.
+        // `parse_partial` returns both the parsed number and, implicitly, the position of the delimiter.
+        let read = match parse_partial::<T>(available) {
+            // If any whitespace was found after parsing, we have a complete number.
+            Ok((number, read))
+                if read != 0 && available.get(read).is_some_and(u8::is_ascii_whitespace) =>
+            {
+                // Consume the number; it doesn't contain any newline.
+                self.reader.consume(read);
+                self.position = position.shifted(read);
+
+                self.try_consume_delimiter(delim)?;
+                return Ok(Some(number));
+            }
+            Ok((_, read)) => read,
+            Err(lexical::Error::Overflow(_) | lexical::Error::Underflow(_)) => {
+                return Err(ReadError::Overflow { position });
+            }
+            Err(error) => error.index().copied().unwrap_or(0),
+        };
+
+        match available.get(read) {
+            // Otherwise, the number might be split on the boundary between two buffers,
+            // so we should return None.
+            // This includes a token that is cut off after a byte that seems invalid,
+            // such as the `N` of a `NaN`.
+            Some(_) if !available[read..].iter().any(u8::is_ascii_whitespace) => Ok(None),
+            None => Ok(None),
+            // Or some byte that cannot be a part of a number was found.
+            Some(&found) => Err(invalid_byte(found, position.shifted(read))),
+        }
+    }
+
+    /// Copies all bytes up to the next whitespace (or the end of the input)
+    /// into the internal buffer of this `U32Reader`, replacing its contents.
+    fn try_copy_token(&mut self) -> Result<(), ReadError> {
+        self.buf.clear();
+
         loop {
-            // Get the internal buffer of the underying reader.
-            let available = match self.reader.fill_buf() {
-                Ok(n) => n,
-                Err(ref e) if e.kind() == io::ErrorKind::Interrupted => continue,
-                Err(e) => Err(e).expect("An input error occured"),
-            };
+            let available = fill_buf(&mut self.reader, self.position)?;
+            if available.is_empty() {
+                return Ok(());
+            }
+
+            let whitespace = available.iter().position(u8::is_ascii_whitespace);
+            let used = whitespace.unwrap_or(available.len());
+
+            // The copied bytes don't contain any newline.
+            self.buf.extend_from_slice(&available[..used]);
+            self.reader.consume(used);
+            self.position = self.position.shifted(used);
 
-            // Parse the first bytes of the buffer into an integer.
-            // `parse_partial` returns both the parsed integer and, implicitly, the position of the delimiter.
-            let (int, read) = lexical::parse_partial::<u32, _>(available)
-                .expect("An error occured when parsing an input integer");
-
-            // If any delimiter was found during parsing, return the parsed integer.
-            // Otherwise, the integer might be split on the boundary between two buffers, so we should return None.
-            return (read < available.len()).then(|| {
-                // Consume the integer and the delimiter byte.
-                self.reader.consume(read + 1);
-                int
-            });
+            if whitespace.is_some() {
+                return Ok(());
+            }
         }
     }
 
-    /// Reads a `u32` from the underlying reader
+    /// Reads a number from the underlying reader
     /// by copying data from the reader into the internal buffer of this `U32Reader`.
     ///
-    /// Can be used even if there are any other bytes between the digits and the delimiter,
-    /// or if the digits are directly followed by EOF.
-    /// For example, if the reader contains `123\r\n` and `delim` is set to `\n`, `123` will be correctly returned.
+    /// Can be used even if the number is split between multiple buffers of the underlying reader,
+    /// or if the number is directly followed by EOF.
+    /// As this copies, use `try_read_until_fast` first.
+    fn try_read_until_fallback<T: Number>(&mut self, delim: Delimiter) -> Result<T, ReadError> {
+        let start = self.position;
+        self.try_copy_token()?;
+
+        // Parse the buffer into a number.
+        let read = match parse_partial::<T>(&self.buf) {
+            Ok((number, read)) if read == self.buf.len() => {
+                self.try_consume_delimiter(delim)?;
+                return Ok(number);
+            }
+            Ok((_, read)) => read,
+            Err(lexical::Error::Overflow(_) | lexical::Error::Underflow(_)) => {
+                return Err(ReadError::Overflow { position: start });
+            }
+            Err(error) => error.index().copied().unwrap_or(0),
+        };
+
+        if read < self.buf.len() {
+            Err(ReadError::InvalidDigit {
+                position: start.shifted(read),
+            })
+        } else {
+            // The number is incomplete (or empty), so the byte after it is at fault.
+            let position = self.position;
+            Err(self
+                .peek_byte()?
+                .map_or(ReadError::UnexpectedEof { position }, |found| {
+                    invalid_byte(found, position)
+                }))
+        }
+    }
+
+    /// Reads a number, that is known to terminate with a space character (`' '`),
+    /// from the underlying reader.
     ///
-    /// Panics if no valid digits can be obtained from the underlying reader,
-    /// if the integer cannot fit into a `u32`,
+    /// # Errors
+    ///
+    /// Returns an error if no valid digits can be obtained from the underlying reader,
+    /// if the number cannot fit into a `T`,
+    /// if the number is not followed by a single space character,
     /// or if any I/O error occured.
-    #[must_use]
-    fn read_until_fallback(&mut self, delim: u8) -> u32 {
-        // Clear the internal buffer for writing new data into it.
-        self.buf.clear();
+    pub fn try_read_until_space<T: Number>(&mut self) -> Result<T, ReadError> {
+        self.try_read_until(Delimiter::Space)
+    }
+
+    /// Reads a number, that is known to terminate with a newline (`\n` or `\r\n`)
+    /// or the end of the input, from the underlying reader.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no valid digits can be obtained from the underlying reader,
+    /// if the number cannot fit into a `T`,
+    /// if the number is not followed by a newline or the end of the input,
+    /// or if any I/O error occured.
+    pub fn try_read_until_newline<T: Number>(&mut self) -> Result<T, ReadError> {
+        self.try_read_until(Delimiter::Newline)
+    }
+
+    /// Reads a row of `n` numbers, separated by single spaces and terminated by a newline
+    /// (`\n` or `\r\n`) or the end of the input, appending them to `row`.
+    ///
+    /// As many numbers as possible are parsed in one pass over the buffer of the underlying reader;
+    /// this is much faster than reading the numbers one by one.
+    ///
+    /// If `n` is zero, an empty line is expected.
+    /// In [`Mode::Lenient`], the row may be wrapped across lines, but must still be followed by
+    /// a line break or the end of the input, so that a row with too many numbers is rejected too.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the row does not contain exactly `n` numbers,
+    /// in addition to the conditions in [`try_read_until_space`](Self::try_read_until_space).
+    /// The numbers read before the error occured are left in `row`.
+    pub fn try_read_row_into<T: Number>(
+        &mut self,
+        row: &mut Vec<T>,
+        n: usize,
+    ) -> Result<(), ReadError> {
+        // `n` usually comes from the input itself, so don't trust it with an allocation:
+        // every number but the last takes up at least two bytes, a digit and a space.
+        let buffered = fill_buf(&mut self.reader, self.position)?.len();
+        row.reserve(n.min(buffered / 2 + 1));
+
+        let Some(mut remaining) = n.checked_sub(1) else {
+            return match self.mode {
+                Mode::Strict => self.try_consume_delimiter(Delimiter::Newline),
+                Mode::Lenient => Ok(()),
+            };
+        };
+
+        // The numbers need not be on the same line, so just read them one by one.
+        if self.mode == Mode::Lenient {
+            for _ in 0..remaining {
+                row.push(self.try_read_until(Delimiter::Space)?);
+            }
+            row.push(self.try_read_until(Delimiter::RowEnd)?);
+            return Ok(());
+        }
 
-        let used = self
-            .reader
-            // Read the decimal-encoded number into the buffer.
-            .read_until(delim, &mut self.buf)
-            .expect("An input error occured");
+        // Read all numbers except the last one, which is terminated by a newline instead.
+        while remaining != 0 {
+            let position = self.position;
+            let available = fill_buf(&mut self.reader, position)?;
+            // Only the current line can hold the rest of the row.
+            let line = &available[..memchr::memchr(b'\n', available).unwrap_or(available.len())];
 
-        assert_ne!(used, 0, "No more integers can be read");
+            // Parse the numbers in the line, until we reach one that is not followed by a space.
+            let mut used = 0;
+            while remaining != 0 {
+                let rest = &line[used..];
+                match parse_partial::<T>(rest) {
+                    Ok((number, read)) if read != 0 && rest.get(read) == Some(&b' ') => {
+                        row.push(number);
+                        used += read + 1;
+                        remaining -= 1;
+                    }
+                    _ => break,
+                }
+            }
 
-        // Parse the input into an integer.
-        // `parse_partial` ignores the delimiter(s) left by `read_until` at the end of the string.
-        lexical::parse_partial(&self.buf)
-            // Panic if any overflow occured.
-            .expect("Input cannot be represented by an unsigned 32-bit integer")
-            .0
+            // Consume the numbers and spaces; they don't contain any newline.
+            self.reader.consume(used);
+            self.position = position.shifted(used);
+
+            // The next number is split between two buffers, or there is some error.
+            // Either way, read it individually.
+            if remaining != 0 {
+                row.push(self.try_read_until(Delimiter::Space)?);
+                remaining -= 1;
+            }
+        }
+
+        row.push(self.try_read_until(Delimiter::Newline)?);
+        Ok(())
+    }
+
+    /// Reads a row of `n` numbers, separated by single spaces and terminated by a newline
+    /// (`\n` or `\r\n`) or the end of the input.
+    ///
+    /// # Errors
+    ///
+    /// See [`try_read_row_into`](Self::try_read_row_into).
+    pub fn try_read_row<T: Number>(&mut self, n: usize) -> Result<Vec<T>, ReadError> {
+        let mut row = Vec::new();
+        self.try_read_row_into(&mut row, n)?;
+        Ok(row)
     }
 
-    /// Reads a `u32`, that is known to terminate with a space character (`' '`),
+    /// Reads a number, followed by the given delimiter, from the underlying reader.
+    fn try_read_until<T: Number>(&mut self, delim: Delimiter) -> Result<T, ReadError> {
+        if self.mode == Mode::Lenient {
+            // There may be any whitespace before the first number.
+            self.try_skip_whitespace()?;
+        }
+
+        self.try_read_until_fast(delim)?
+            .map_or_else(|| self.try_read_until_fallback(delim), Ok)
+    }
+
+    /// Reads a number, that is known to terminate with a space character (`' '`),
     /// from the underlying reader.
     ///
-    /// Panics if no valid digits can be obtained from the underlying reader,
-    /// if the integer cannot fit into a `u32`,
-    /// or if any I/O error occured.
+    /// # Panics
+    ///
+    /// Panics if [`try_read_until_space`](Self::try_read_until_space) returns an error.
     #[must_use]
-    pub fn read_until_space(&mut self) -> u32 {
-        self.read_until_fast()
-            .unwrap_or_else(|| self.read_until_fallback(b' '))
+    pub fn read_until_space<T: Number>(&mut self) -> T {
+        self.try_read_until_space()
+            .unwrap_or_else(|error| panic!("Failed to read an input number: {error}"))
     }
 
-    /// Reads a `u32`, that is known to terminate with a newline (`\n` or `\r\n`),
-    /// from the underlying reader.
+    /// Reads a number, that is known to terminate with a newline (`\n` or `\r\n`)
+    /// or the end of the input, from the underlying reader.
     ///
-    /// Panics if no valid digits can be obtained from the underlying reader,
-    /// if the integer cannot fit into a `u32`,
-    /// or if any I/O error occured.
+    /// # Panics
+    ///
+    /// Panics if [`try_read_until_newline`](Self::try_read_until_newline) returns an error.
     #[must_use]
-    pub fn read_until_newline(&mut self) -> u32 {
-        self.read_until_fallback(b'\n')
+    pub fn read_until_newline<T: Number>(&mut self) -> T {
+        self.try_read_until_newline()
+            .unwrap_or_else(|error| panic!("Failed to read an input number: {error}"))
+    }
+
+    /// Reads a row of `n` numbers, separated by single spaces and terminated by a newline
+    /// (`\n` or `\r\n`) or the end of the input, appending them to `row`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`try_read_row_into`](Self::try_read_row_into) returns an error.
+    pub fn read_row_into<T: Number>(&mut self, row: &mut Vec<T>, n: usize) {
+        self.try_read_row_into(row, n)
+            .unwrap_or_else(|error| panic!("Failed to read a row of input numbers: {error}"));
+    }
+
+    /// Reads a row of `n` numbers, separated by single spaces and terminated by a newline
+    /// (`\n` or `\r\n`) or the end of the input.
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`try_read_row`](Self::try_read_row) returns an error.
+    #[must_use]
+    pub fn read_row<T: Number>(&mut self, n: usize) -> Vec<T> {
+        self.try_read_row(n)
+            .unwrap_or_else(|error| panic!("Failed to read a row of input numbers: {error}"))
+    }
+}
+
+/// A [`U32Reader`] over input that is entirely in memory.
+///
+/// As the whole input is always in the buffer, numbers are never split between buffers,
+/// so every number is parsed in place and nothing is copied.
+///
+/// Use [`slurp`] to get the entire input in memory in the first place.
+pub type SliceReader<'a> = U32Reader<&'a [u8]>;
+
+/// Reads the entire input from the given reader into memory, to be read by a [`SliceReader`].
+///
+/// # Errors
+///
+/// Returns an error if any I/O error occured.
+pub fn slurp(mut reader: impl Read) -> io::Result<Vec<u8>> {
+    let mut input = Vec::new();
+    reader.read_to_end(&mut input)?;
+    Ok(input)
+}
+
+/// Returns the internal buffer of the reader, retrying if the read was interrupted.
+fn fill_buf<R: io::BufRead>(reader: &mut R, position: Position) -> Result<&[u8], ReadError> {
+    loop {
+        match reader.fill_buf() {
+            Err(ref e) if e.kind() == io::ErrorKind::Interrupted => {}
+            Err(error) => return Err(ReadError::Io { error, position }),
+            Ok(_) => break,
+        }
+    }
+
+    // This returns the same buffer without reading again;
+    // the borrow checker does not allow returning the buffer from within the loop.
+    reader
+        .fill_buf()
+        .map_err(|error| ReadError::Io { error, position })
+}
+
+/// Returns the error for an unexpected byte, given that it cannot be a part of a number.
+const fn invalid_byte(found: u8, position: Position) -> ReadError {
+    if found.is_ascii_whitespace() {
+        ReadError::UnexpectedDelimiter { found, position }
+    } else {
+        ReadError::InvalidDigit { position }
+    }
+}
+
+/// Parses a number at the start of `bytes`, returning it and the number of bytes it takes up,
+/// like `lexical::parse_partial`.
+///
+/// Unlike `lexical`, which reads a lone sign followed by a non-digit as zero,
+/// this requires at least one digit.
+fn parse_partial<T: Number>(bytes: &[u8]) -> lexical::Result<(T, usize)> {
+    match lexical::parse_partial(bytes) {
+        Ok((_, 1)) if matches!(bytes[0], b'+' | b'-') => Err(lexical::Error::Empty(1)),
+        result => result,
     }
 }
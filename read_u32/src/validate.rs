@@ -0,0 +1,312 @@
+use std::{error, fmt, io, ops::RangeInclusive};
+
+use crate::{fill_buf, Position, ReadError};
+
+/// A checker that input data follows a format exactly.
+///
+/// Unlike [`U32Reader`](crate::U32Reader), which accepts anything it can make sense of,
+/// this rejects anything that is not exactly in the expected format,
+/// such as leading zeros, `\r\n` newlines, trailing whitespace or a missing newline at the end.
+/// This is for checking test data, not for solving problems.
+#[derive(Clone, Debug)]
+pub struct Validator<R: io::BufRead> {
+    reader: R,
+    position: Position,
+}
+
+impl<R: io::BufRead> Validator<R> {
+    /// Creates a new validator that checks the data from the given reader.
+    #[must_use]
+    pub const fn new(reader: R) -> Self {
+        Self {
+            reader,
+            position: Position::START,
+        }
+    }
+
+    /// Returns the position of the next byte to be checked.
+    #[must_use]
+    pub const fn position(&self) -> Position {
+        self.position
+    }
+
+    /// Returns the next byte without consuming it, or `None` at the end of the input.
+    fn peek_byte(&mut self) -> Result<Option<u8>, ValidationError> {
+        Ok(fill_buf(&mut self.reader, self.position)?.first().copied())
+    }
+
+    /// Consumes a byte previously returned by `peek_byte`.
+    fn consume_byte(&mut self, byte: u8) {
+        self.reader.consume(1);
+        self.position.advance(&[byte]);
+    }
+
+    /// Consumes the given byte, or returns an error if the next byte is something else.
+    fn expect_byte(&mut self, byte: u8, expected: &'static str) -> Result<(), ValidationError> {
+        let position = self.position;
+        match self.peek_byte()? {
+            Some(found) if found == byte => {
+                self.consume_byte(found);
+                Ok(())
+            }
+            found => Err(ValidationError::Expected {
+                expected,
+                found,
+                position,
+            }),
+        }
+    }
+
+    /// Consumes bytes until the next byte satisfying `is_end` or the end of the input,
+    /// and returns them.
+    fn take_until(&mut self, is_end: impl Fn(u8) -> bool) -> Result<Vec<u8>, ValidationError> {
+        let mut taken = Vec::new();
+        loop {
+            let available = fill_buf(&mut self.reader, self.position)?;
+            let end = available.iter().position(|&byte| is_end(byte));
+            let used = end.unwrap_or(available.len());
+
+            taken.extend_from_slice(&available[..used]);
+            self.position.advance(&available[..used]);
+            self.reader.consume(used);
+
+            if end.is_some() || used == 0 {
+                return Ok(taken);
+            }
+        }
+    }
+
+    /// Checks that the next bytes are a non-empty run of non-whitespace bytes, and returns them.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the next byte is whitespace or the end of the input,
+    /// or if any I/O error occured.
+    pub fn expect_token(&mut self) -> Result<Vec<u8>, ValidationError> {
+        let position = self.position;
+        let token = self.take_until(|byte| byte.is_ascii_whitespace())?;
+        if token.is_empty() {
+            return Err(ValidationError::Expected {
+                expected: "a token",
+                found: self.peek_byte()?,
+                position,
+            });
+        }
+        Ok(token)
+    }
+
+    /// Checks that the next bytes are the rest of the current line, and returns them.
+    ///
+    /// The line may be empty, and the newline itself is not consumed;
+    /// use [`expect_eoln`](Self::expect_eoln) for that.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the line contains a `\r` or ends with a space or tab,
+    /// or if any I/O error occured.
+    pub fn expect_line(&mut self) -> Result<Vec<u8>, ValidationError> {
+        let start = self.position;
+        let line = self.take_until(|byte| byte == b'\n' || byte == b'\r')?;
+        if let Some(&last @ (b' ' | b'\t')) = line.last() {
+            return Err(ValidationError::Expected {
+                expected: "a newline ('\\n')",
+                found: Some(last),
+                position: start.shifted(line.len() - 1),
+            });
+        }
+        if self.peek_byte()? == Some(b'\r') {
+            // Report it as the wrong newline, like `expect_eoln` does.
+            self.expect_eoln()?;
+        }
+        Ok(line)
+    }
+
+    /// Checks that the next bytes are a decimal integer in the given range, and returns it.
+    ///
+    /// The integer must consist of ASCII digits only, without any sign or leading zeros.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the next bytes are not a decimal integer,
+    /// if the integer is not in `range`,
+    /// or if any I/O error occured.
+    pub fn expect_u32_in(&mut self, range: RangeInclusive<u32>) -> Result<u32, ValidationError> {
+        let position = self.position;
+        let digits = self.take_until(|byte| !byte.is_ascii_digit())?;
+
+        match digits.as_slice() {
+            [] => {
+                return Err(ValidationError::Expected {
+                    expected: "a digit",
+                    found: self.peek_byte()?,
+                    position,
+                })
+            }
+            [b'0', _, ..] => {
+                return Err(ValidationError::Invalid {
+                    message: "leading zeros are not allowed".into(),
+                    position,
+                })
+            }
+            _ => {}
+        }
+
+        // The digits can be parsed into some integer, but it may be too large.
+        lexical::parse(&digits)
+            .ok()
+            .filter(|value| range.contains(value))
+            .ok_or_else(|| ValidationError::OutOfRange {
+                value: String::from_utf8_lossy(&digits).into_owned(),
+                range,
+                position,
+            })
+    }
+
+    /// Checks that the next bytes are a floating point number, and returns it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the next bytes are not a floating point number,
+    /// or if any I/O error occured.
+    pub fn expect_f64(&mut self) -> Result<f64, ValidationError> {
+        let position = self.position;
+        let token = self.expect_token()?;
+        lexical::parse(&token).map_err(|_| ValidationError::Invalid {
+            message: format!(
+                "'{token}' is not a floating point number",
+                token = token.escape_ascii(),
+            ),
+            position,
+        })
+    }
+
+    /// Checks that the next byte is a single space character (`' '`), and consumes it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the next byte is not a space, or if any I/O error occured.
+    pub fn expect_space(&mut self) -> Result<(), ValidationError> {
+        self.expect_byte(b' ', "a space")
+    }
+
+    /// Checks that the next byte is a single `\n`, and consumes it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the next byte is not `\n`, or if any I/O error occured.
+    pub fn expect_eoln(&mut self) -> Result<(), ValidationError> {
+        self.expect_byte(b'\n', "a newline ('\\n')")
+    }
+
+    /// Checks that the end of the input has been reached.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there are any bytes left, or if any I/O error occured.
+    pub fn expect_eof(&mut self) -> Result<(), ValidationError> {
+        let position = self.position;
+        match self.peek_byte()? {
+            None => Ok(()),
+            found => Err(ValidationError::Expected {
+                expected: "the end of the input",
+                found,
+                position,
+            }),
+        }
+    }
+}
+
+/// A violation of the input format, found by a [`Validator`].
+#[derive(Debug)]
+pub enum ValidationError {
+    /// The underlying reader returned an error.
+    Io {
+        error: io::Error,
+        position: Position,
+    },
+    /// Something else was found where a specific byte or kind of token was expected.
+    Expected {
+        /// A description of what was expected.
+        expected: &'static str,
+        /// The byte found instead, or `None` at the end of the input.
+        found: Option<u8>,
+        position: Position,
+    },
+    /// An integer is outside the range of allowed values.
+    OutOfRange {
+        value: String,
+        range: RangeInclusive<u32>,
+        position: Position,
+    },
+    /// Any other violation, such as that of a problem-specific constraint.
+    Invalid { message: String, position: Position },
+}
+
+impl ValidationError {
+    /// Creates an error for a problem-specific constraint violated at `position`.
+    pub fn invalid(message: impl Into<String>, position: Position) -> Self {
+        Self::Invalid {
+            message: message.into(),
+            position,
+        }
+    }
+
+    /// Returns the position in the input where the violation was found.
+    #[must_use]
+    pub const fn position(&self) -> Position {
+        match *self {
+            Self::Io { position, .. }
+            | Self::Expected { position, .. }
+            | Self::OutOfRange { position, .. }
+            | Self::Invalid { position, .. } => position,
+        }
+    }
+}
+
+impl From<ReadError> for ValidationError {
+    fn from(error: ReadError) -> Self {
+        match error {
+            ReadError::Io { error, position } => Self::Io { error, position },
+            other => Self::invalid(other.to_string(), other.position()),
+        }
+    }
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{position}: ", position = self.position())?;
+        match self {
+            Self::Io { error, .. } => write!(f, "an input error occured: {error}"),
+            Self::Expected {
+                expected,
+                found: Some(found),
+                ..
+            } => write!(
+                f,
+                "expected {expected}, found '{found}'",
+                found = found.escape_ascii(),
+            ),
+            Self::Expected {
+                expected,
+                found: None,
+                ..
+            } => write!(f, "expected {expected}, found the end of the input"),
+            Self::OutOfRange { value, range, .. } => write!(
+                f,
+                "{value} is not between {min} and {max}",
+                min = range.start(),
+                max = range.end(),
+            ),
+            Self::Invalid { message, .. } => f.write_str(message),
+        }
+    }
+}
+
+impl error::Error for ValidationError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::Io { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use std::io::BufReader;
+
+use read_u32::U32Reader;
+
+/// Reads numbers from the input, with every possible small buffer size of the underlying reader,
+/// so that numbers and newlines are split between buffers in every possible way.
+fn read_all_capacities(input: &str, read: impl Fn(&mut U32Reader<BufReader<&[u8]>>)) {
+    for capacity in 1..=input.len() + 1 {
+        let mut reader = U32Reader::new(BufReader::with_capacity(capacity, input.as_bytes()));
+        read(&mut reader);
+        assert!(
+            reader.try_read_until_newline::<u32>().is_err(),
+            "input not fully consumed with capacity {capacity}",
+        );
+    }
+}
+
+#[test]
+fn newlines() {
+    read_all_capacities("123\n45\n6\n7890", |reader| {
+        assert_eq!(reader.read_until_newline::<u32>(), 123);
+        assert_eq!(reader.read_until_newline::<u32>(), 45);
+        assert_eq!(reader.read_until_newline::<u32>(), 6);
+        assert_eq!(reader.read_until_newline::<u32>(), 7890);
+    });
+}
+
+#[test]
+fn windows_newlines() {
+    read_all_capacities("123\r\n45\r\n6\r\n7890\r\n", |reader| {
+        assert_eq!(reader.read_until_newline::<u32>(), 123);
+        assert_eq!(reader.read_until_newline::<u32>(), 45);
+        assert_eq!(reader.read_until_newline::<u32>(), 6);
+        assert_eq!(reader.read_until_newline::<u32>(), 7890);
+    });
+}
+
+#[test]
+fn mixed() {
+    read_all_capacities("3\r\n10 200\n3000 4\r\n", |reader| {
+        assert_eq!(reader.read_until_newline::<u32>(), 3);
+        assert_eq!(reader.read_until_space::<u32>(), 10);
+        assert_eq!(reader.read_until_newline::<u32>(), 200);
+        assert_eq!(reader.read_until_space::<u32>(), 3000);
+        assert_eq!(reader.read_until_newline::<u32>(), 4);
+    });
+}
+
+#[test]
+fn special_floats() {
+    read_all_capacities("NaN inf\n-Infinity\n", |reader| {
+        assert!(reader.read_until_space::<f64>().is_nan());
+        let infinity: f64 = reader.read_until_newline();
+        assert!(infinity.is_infinite() && infinity.is_sign_positive());
+        let infinity: f64 = reader.read_until_newline();
+        assert!(infinity.is_infinite() && infinity.is_sign_negative());
+    });
+}
@@ -0,0 +1,102 @@
+use std::io::BufReader;
+
+use read_u32::{Mode, Position, ReadError, U32Reader};
+
+/// Runs `read` with every possible small buffer size of the underlying reader,
+/// so that tokens and newlines are split between buffers in every possible way.
+fn read_all_capacities(input: &str, read: impl Fn(&mut U32Reader<BufReader<&[u8]>>)) {
+    for capacity in 1..=input.len() + 1 {
+        let mut reader = U32Reader::new(BufReader::with_capacity(capacity, input.as_bytes()));
+        read(&mut reader);
+        assert!(
+            matches!(
+                reader.try_read_line_bytes(),
+                Err(ReadError::UnexpectedEof { .. }),
+            ),
+            "input not fully consumed with capacity {capacity}",
+        );
+    }
+}
+
+#[test]
+fn tokens() {
+    read_all_capacities("IN\r\nHELLO WORLD\n1 2\n", |reader| {
+        assert_eq!(reader.read_token(), b"IN");
+        assert_eq!(reader.read_token(), b"HELLO");
+        assert_eq!(reader.read_token(), b"WORLD");
+        assert_eq!(reader.read_token(), b"1");
+        assert_eq!(reader.read_until_newline::<u32>(), 2);
+    });
+}
+
+#[test]
+fn lines() {
+    read_all_capacities("Hello world\r\n\n 3 \nlast", |reader| {
+        assert_eq!(reader.read_line_bytes(), b"Hello world");
+        assert_eq!(reader.read_line_bytes(), b"");
+        assert_eq!(reader.read_line_bytes(), b" 3 ");
+        assert_eq!(reader.read_line_bytes(), b"last");
+    });
+}
+
+#[test]
+fn fixed_rows() {
+    read_all_capacities("2\n10010\r\n1x1\n", |reader| {
+        assert_eq!(reader.read_until_newline::<u32>(), 2);
+        assert_eq!(reader.read_fixed_row::<5>(), b"10010");
+        assert_eq!(reader.read_fixed_row::<3>(), b"1x1");
+    });
+}
+
+#[test]
+fn wrong_length() {
+    let mut reader = U32Reader::new(&b"123\n12345\n"[..]);
+    assert!(matches!(
+        reader.try_read_fixed_row::<4>(),
+        Err(ReadError::WrongLength { expected: 4, found: 3, position }) if position == Position::START,
+    ));
+    assert!(matches!(
+        reader.try_read_fixed_row::<4>(),
+        Err(ReadError::WrongLength {
+            expected: 4,
+            found: 5,
+            ..
+        }),
+    ));
+
+    // A row must not contain spaces.
+    assert!(matches!(
+        U32Reader::new(&b"12 34\n"[..]).try_read_fixed_row::<5>(),
+        Err(ReadError::UnexpectedDelimiter { found: b' ', .. }),
+    ));
+}
+
+#[test]
+fn empty_tokens() {
+    assert!(matches!(
+        U32Reader::new(&b""[..]).try_read_token(),
+        Err(ReadError::UnexpectedEof { .. }),
+    ));
+    assert!(matches!(
+        U32Reader::new(&b" IN"[..]).try_read_token(),
+        Err(ReadError::UnexpectedDelimiter { found: b' ', .. }),
+    ));
+    assert!(matches!(
+        U32Reader::new(&b"IN\tOUT"[..]).try_read_token(),
+        Err(ReadError::UnexpectedDelimiter { found: b'\t', .. }),
+    ));
+}
+
+#[test]
+fn lenient() {
+    let input = b"  IN \t\r\n\n 10010  \n";
+    for capacity in 1..=input.len() {
+        let mut reader = U32Reader::with_mode(
+            BufReader::with_capacity(capacity, &input[..]),
+            Mode::Lenient,
+        );
+        assert_eq!(reader.read_token(), b"IN");
+        assert_eq!(reader.read_fixed_row::<5>(), b"10010");
+        assert!(reader.try_read_token().is_err());
+    }
+}
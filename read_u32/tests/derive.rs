@@ -0,0 +1,114 @@
+use read_u32::{KickstartInput, ReadError, U32Reader};
+
+#[derive(Debug, PartialEq, KickstartInput)]
+struct Input {
+    #[line]
+    test_case_count: usize,
+    #[count_from = "test_case_count"]
+    test_cases: Vec<TestCase>,
+}
+
+#[derive(Debug, PartialEq, KickstartInput)]
+struct TestCase {
+    #[sep = "space"]
+    price: u32,
+    #[line]
+    wallet: u32,
+}
+
+#[test]
+fn test_cases() {
+    let input = Input::read_from(&mut U32Reader::new(&b"2\n1 3\r\n1 11"[..]));
+    assert_eq!(
+        input,
+        Input {
+            test_case_count: 2,
+            test_cases: vec![
+                TestCase {
+                    price: 1,
+                    wallet: 3
+                },
+                TestCase {
+                    price: 1,
+                    wallet: 11
+                },
+            ],
+        },
+    );
+}
+
+#[derive(Debug, PartialEq, KickstartInput)]
+struct Row {
+    #[line]
+    n: usize,
+    #[count_from = "n"]
+    #[line]
+    numbers: Vec<u64>,
+    // Fields are separated by spaces, and the last one ends the line.
+    a: i32,
+    b: i32,
+}
+
+#[test]
+fn rows_and_defaults() {
+    let row = Row::read_from(&mut U32Reader::new(&b"3\n10 20 30\n-1 2\n"[..]));
+    assert_eq!(
+        row,
+        Row {
+            n: 3,
+            numbers: vec![10, 20, 30],
+            a: -1,
+            b: 2,
+        },
+    );
+}
+
+#[derive(Debug, PartialEq, KickstartInput)]
+struct Prefixed {
+    #[count_prefixed]
+    test_cases: Vec<TestCase>,
+    #[count_prefixed]
+    #[line]
+    numbers: Vec<u32>,
+}
+
+#[test]
+fn count_prefixed() {
+    let prefixed = Prefixed::read_from(&mut U32Reader::new(&b"1\n1 3\n2\n4 5\n"[..]));
+    assert_eq!(
+        prefixed,
+        Prefixed {
+            test_cases: vec![TestCase {
+                price: 1,
+                wallet: 3
+            }],
+            numbers: vec![4, 5],
+        },
+    );
+
+    // The count is on a line of its own.
+    assert!(matches!(
+        Prefixed::try_read_from(&mut U32Reader::new(&b"1 1 3\n0\n\n"[..])),
+        Err(ReadError::UnexpectedDelimiter { found: b' ', .. }),
+    ));
+}
+
+#[test]
+fn errors() {
+    // Too few test cases.
+    assert!(matches!(
+        Input::try_read_from(&mut U32Reader::new(&b"2\n1 3\n"[..])),
+        Err(ReadError::UnexpectedEof { .. }),
+    ));
+    // A newline where a space is expected.
+    assert!(matches!(
+        Row::try_read_from(&mut U32Reader::new(&b"1\n5\n-1\n2\n"[..])),
+        Err(ReadError::UnexpectedDelimiter { found: b'\n', .. }),
+    ));
+
+    // A count far beyond the input is an error, not an allocation failure.
+    assert!(matches!(
+        Row::try_read_from(&mut U32Reader::new(&b"4000000000\n5\n-1 2\n"[..])),
+        Err(ReadError::UnexpectedDelimiter { found: b'\n', .. }),
+    ));
+}
@@ -0,0 +1,128 @@
+use read_u32::{Position, ReadError, U32Reader};
+
+const fn at(offset: u64, line: u64, column: u64) -> Position {
+    Position {
+        offset,
+        line,
+        column,
+    }
+}
+
+#[test]
+fn positions() {
+    let mut reader = U32Reader::new(&b"2\r\n10 20\n"[..]);
+    assert_eq!(reader.position(), Position::START);
+    assert_eq!(reader.try_read_until_newline::<u32>().unwrap(), 2);
+    assert_eq!(reader.position(), at(3, 2, 1));
+    assert_eq!(reader.try_read_until_space::<u32>().unwrap(), 10);
+    assert_eq!(reader.position(), at(6, 2, 4));
+    assert_eq!(reader.try_read_until_newline::<u32>().unwrap(), 20);
+    assert_eq!(reader.position(), at(9, 3, 1));
+}
+
+#[test]
+fn unexpected_eof() {
+    let mut reader = U32Reader::new(&b"1\n"[..]);
+    assert_eq!(reader.try_read_until_newline::<u32>().unwrap(), 1);
+    assert!(matches!(
+        reader.try_read_until_newline::<u32>(),
+        Err(ReadError::UnexpectedEof { position }) if position == at(2, 2, 1),
+    ));
+
+    // A number followed by a space must not be the last one.
+    assert!(matches!(
+        U32Reader::new(&b"1"[..]).try_read_until_space::<u32>(),
+        Err(ReadError::UnexpectedEof { position }) if position == at(1, 1, 2),
+    ));
+}
+
+#[test]
+fn overflow() {
+    let mut reader = U32Reader::new(&b"1\n4294967296 1\n"[..]);
+    assert_eq!(reader.try_read_until_newline::<u32>().unwrap(), 1);
+    assert!(matches!(
+        reader.try_read_until_space::<u32>(),
+        Err(ReadError::Overflow { position }) if position == at(2, 2, 1),
+    ));
+}
+
+#[test]
+fn invalid_digit() {
+    let mut reader = U32Reader::new(&b"12 3x4\n"[..]);
+    assert_eq!(reader.try_read_until_space::<u32>().unwrap(), 12);
+    assert!(matches!(
+        reader.try_read_until_newline::<u32>(),
+        Err(ReadError::InvalidDigit { position }) if position == at(4, 1, 5),
+    ));
+
+    assert!(matches!(
+        U32Reader::new(&b"-1 2\n"[..]).try_read_until_space::<u32>(),
+        Err(ReadError::InvalidDigit { position }) if position == Position::START,
+    ));
+}
+
+#[test]
+fn lone_sign() {
+    // A sign is not a number without any digits after it, even when followed by a delimiter.
+    assert!(matches!(
+        U32Reader::new(&b"+ 1\n"[..]).try_read_until_space::<u32>(),
+        Err(ReadError::UnexpectedDelimiter { found: b' ', position }) if position == at(1, 1, 2),
+    ));
+    assert!(matches!(
+        U32Reader::new(&b"-\n"[..]).try_read_until_newline::<i64>(),
+        Err(ReadError::UnexpectedDelimiter { found: b'\n', position }) if position == at(1, 1, 2),
+    ));
+    assert!(matches!(
+        U32Reader::new(&b"1 + 3\n"[..]).try_read_row::<u32>(3),
+        Err(ReadError::UnexpectedDelimiter { found: b' ', position }) if position == at(3, 1, 4),
+    ));
+}
+
+#[test]
+fn unexpected_delimiter() {
+    // A newline where a space is expected.
+    assert!(matches!(
+        U32Reader::new(&b"12\n34\n"[..]).try_read_until_space::<u32>(),
+        Err(ReadError::UnexpectedDelimiter { found: b'\n', position }) if position == at(2, 1, 3),
+    ));
+
+    // A space where a newline is expected.
+    assert!(matches!(
+        U32Reader::new(&b"12 34\n"[..]).try_read_until_newline::<u32>(),
+        Err(ReadError::UnexpectedDelimiter { found: b' ', position }) if position == at(2, 1, 3),
+    ));
+
+    // Two spaces between numbers.
+    let mut reader = U32Reader::new(&b"12  34\n"[..]);
+    assert_eq!(reader.try_read_until_space::<u32>().unwrap(), 12);
+    assert!(matches!(
+        reader.try_read_until_newline::<u32>(),
+        Err(ReadError::UnexpectedDelimiter { found: b' ', position }) if position == at(3, 1, 4),
+    ));
+}
+
+#[test]
+fn trailing_blanks() {
+    // Spaces or tabs before a newline or the end of the input, which the first readers accepted.
+    for input in [&b"5 \n7\n"[..], b"5\t \n7\n", b"5  \r\n7"] {
+        let mut reader = U32Reader::new(input);
+        assert_eq!(reader.try_read_until_newline::<u32>().unwrap(), 5);
+        assert_eq!(reader.try_read_until_newline::<u32>().unwrap(), 7);
+    }
+    assert_eq!(
+        U32Reader::new(&b"5 "[..]).try_read_row::<u32>(1).unwrap(),
+        [5]
+    );
+    assert_eq!(
+        U32Reader::new(&b"1 2 3 \n"[..])
+            .try_read_row::<u32>(3)
+            .unwrap(),
+        [1, 2, 3]
+    );
+
+    // Blanks followed by more of the line are still reported where they start.
+    assert!(matches!(
+        U32Reader::new(&b"5 \t6\n"[..]).try_read_until_newline::<u32>(),
+        Err(ReadError::UnexpectedDelimiter { found: b' ', position }) if position == at(1, 1, 2),
+    ));
+}
@@ -0,0 +1,54 @@
+use std::io::BufReader;
+
+use read_u32::{Mode, ReadError, U32Reader};
+
+#[test]
+fn messy_whitespace() {
+    let input = b"\n  3 \t\r\n10  20\t \n\n30   \r\n";
+    for capacity in 1..=input.len() {
+        let mut reader = U32Reader::with_mode(
+            BufReader::with_capacity(capacity, &input[..]),
+            Mode::Lenient,
+        );
+        assert_eq!(reader.read_until_newline::<u32>(), 3);
+        assert_eq!(reader.read_until_space::<u32>(), 10);
+        assert_eq!(reader.read_until_newline::<u32>(), 20);
+        assert_eq!(reader.read_until_newline::<u32>(), 30);
+        assert!(matches!(
+            reader.try_read_until_newline::<u32>(),
+            Err(ReadError::UnexpectedEof { .. }),
+        ));
+    }
+}
+
+#[test]
+fn wrapped_rows() {
+    let mut reader = U32Reader::with_mode(&b"1 2\n3\n  4 5 6 \n"[..], Mode::Lenient);
+    assert_eq!(reader.read_row::<u32>(3), [1, 2, 3]);
+    assert_eq!(reader.read_row::<u32>(3), [4, 5, 6]);
+
+    // Even a wrapped row must end where its last line does.
+    let mut reader = U32Reader::with_mode(&b"1\n2 3 4\n"[..], Mode::Lenient);
+    assert!(matches!(
+        reader.try_read_row::<u32>(2),
+        Err(ReadError::UnexpectedDelimiter { found: b' ', position }) if position.offset == 3,
+    ));
+    let mut reader = U32Reader::with_mode(&b"1\n2\t\r\n3 4"[..], Mode::Lenient);
+    assert_eq!(reader.read_row::<u32>(2), [1, 2]);
+    assert_eq!(reader.read_row::<u32>(2), [3, 4]);
+}
+
+#[test]
+fn still_rejects_garbage() {
+    let mut reader = U32Reader::with_mode(&b"  12x 3\n"[..], Mode::Lenient);
+    assert!(matches!(
+        reader.try_read_until_space::<u32>(),
+        Err(ReadError::InvalidDigit { .. }),
+    ));
+}
+
+#[test]
+fn strict_by_default() {
+    assert_eq!(Mode::default(), Mode::Strict);
+    assert_eq!(U32Reader::new(&b""[..]).mode(), Mode::Strict);
+}
@@ -0,0 +1,34 @@
+use read_u32::U32Reader;
+
+#[test]
+fn unsigned() {
+    let mut reader = U32Reader::new(&b"255 65535\n4294967295 18446744073709551615\n"[..]);
+    assert_eq!(reader.read_until_space::<u8>(), u8::MAX);
+    assert_eq!(reader.read_until_newline::<u16>(), u16::MAX);
+    assert_eq!(reader.read_until_space::<u32>(), u32::MAX);
+    assert_eq!(reader.read_until_newline::<u64>(), u64::MAX);
+}
+
+#[test]
+fn signed() {
+    let mut reader = U32Reader::new(&b"-128 -1\r\n-9223372036854775808 42"[..]);
+    assert_eq!(reader.read_until_space::<i8>(), i8::MIN);
+    assert_eq!(reader.read_until_newline::<isize>(), -1);
+    assert_eq!(reader.read_until_space::<i64>(), i64::MIN);
+    assert_eq!(reader.read_until_newline::<i128>(), 42);
+}
+
+#[test]
+fn floating_point() {
+    let mut reader = U32Reader::new(&b"36.5 -0.25\n3.65e1\r\n1"[..]);
+    assert!((reader.read_until_space::<f64>() - 36.5).abs() < f64::EPSILON);
+    assert!((reader.read_until_newline::<f32>() + 0.25).abs() < f32::EPSILON);
+    assert!((reader.read_until_newline::<f64>() - 36.5).abs() < f64::EPSILON);
+    assert!((reader.read_until_newline::<f64>() - 1.0).abs() < f64::EPSILON);
+}
+
+#[test]
+#[should_panic(expected = "cannot be represented by the requested type")]
+fn overflow() {
+    let _: u8 = U32Reader::new(&b"256\n"[..]).read_until_newline();
+}
@@ -0,0 +1,58 @@
+use std::io::BufReader;
+
+use read_u32::{Mode, ReadError, U32Reader};
+
+#[test]
+fn rows() {
+    let mut reader = U32Reader::new(&b"1 2 3\r\n4\n\n5 6"[..]);
+    assert_eq!(reader.read_row::<u32>(3), [1, 2, 3]);
+    assert_eq!(reader.read_row::<u32>(1), [4]);
+    assert_eq!(reader.read_row::<u32>(0), []);
+
+    let mut row = vec![0];
+    reader.read_row_into::<u32>(&mut row, 2);
+    assert_eq!(row, [0, 5, 6]);
+}
+
+#[test]
+fn small_buffers() {
+    let input = (1..=100)
+        .map(|n| n.to_string())
+        .collect::<Vec<_>>()
+        .join(" ")
+        + "\n";
+    let expected: Vec<u32> = (1..=100).collect();
+
+    for capacity in 1..=16 {
+        let mut reader = U32Reader::new(BufReader::with_capacity(capacity, input.as_bytes()));
+        assert_eq!(reader.read_row::<u32>(100), expected);
+    }
+}
+
+#[test]
+fn wrong_length() {
+    // Too few numbers.
+    assert!(matches!(
+        U32Reader::new(&b"1 2\n3\n"[..]).try_read_row::<u32>(3),
+        Err(ReadError::UnexpectedDelimiter { found: b'\n', .. }),
+    ));
+
+    // Too many numbers.
+    assert!(matches!(
+        U32Reader::new(&b"1 2 3 4\n"[..]).try_read_row::<u32>(3),
+        Err(ReadError::UnexpectedDelimiter { found: b' ', .. }),
+    ));
+}
+
+#[test]
+fn untrusted_length() {
+    // A count from the input that is far larger than the input must not be allocated up front.
+    for mode in [Mode::Strict, Mode::Lenient] {
+        let mut reader = U32Reader::with_mode(&b"1 2 3\n"[..], mode);
+        assert!(matches!(
+            reader.try_read_row::<u32>(4_000_000_000),
+            Err(ReadError::UnexpectedDelimiter { found: b'\n', .. }
+                | ReadError::UnexpectedEof { .. }),
+        ));
+    }
+}
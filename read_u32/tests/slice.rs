@@ -0,0 +1,23 @@
+use std::io::BufReader;
+
+use read_u32::{slurp, SliceReader};
+
+#[test]
+fn slurped() {
+    // Any reader, even one with a tiny buffer, can be slurped.
+    let input = slurp(BufReader::with_capacity(1, &b"3\n10 20 30\r\nIN\n"[..])).unwrap();
+
+    let mut reader = SliceReader::new(&input);
+    let count = reader.read_until_newline();
+    assert_eq!(reader.read_row::<u32>(count), [10, 20, 30]);
+    assert_eq!(reader.read_token(), b"IN");
+    assert!(reader.try_read_token().is_err());
+}
+
+#[test]
+fn last_number() {
+    // The last number is directly followed by the end of the input.
+    let mut reader = SliceReader::new(b"1 2");
+    assert_eq!(reader.read_until_space::<u32>(), 1);
+    assert_eq!(reader.read_until_newline::<u32>(), 2);
+}
@@ -0,0 +1,161 @@
+use read_u32::{Position, ValidationError, Validator};
+
+const fn at(offset: u64, line: u64, column: u64) -> Position {
+    Position {
+        offset,
+        line,
+        column,
+    }
+}
+
+#[test]
+fn exact_format() {
+    let mut validator = Validator::new(&b"2\n10 hello\n"[..]);
+    assert_eq!(validator.expect_u32_in(1..=2).unwrap(), 2);
+    validator.expect_eoln().unwrap();
+    assert_eq!(validator.expect_u32_in(0..=10).unwrap(), 10);
+    validator.expect_space().unwrap();
+    assert_eq!(validator.expect_token().unwrap(), b"hello");
+    validator.expect_eoln().unwrap();
+    validator.expect_eof().unwrap();
+    assert_eq!(validator.position(), at(11, 3, 1));
+}
+
+#[test]
+fn lines() {
+    let mut validator = Validator::new(&b"Hello world\n\n"[..]);
+    assert_eq!(validator.expect_line().unwrap(), b"Hello world");
+    validator.expect_eoln().unwrap();
+    assert_eq!(validator.expect_line().unwrap(), b"");
+    validator.expect_eoln().unwrap();
+    validator.expect_eof().unwrap();
+}
+
+#[test]
+fn line_endings() {
+    // Trailing whitespace, reported where it starts to be trailing.
+    for input in [&b"Hello world \n"[..], b"Hello world\t\n", b"Hello world "] {
+        assert!(matches!(
+            Validator::new(input).expect_line(),
+            Err(ValidationError::Expected { found: Some(b' ' | b'\t'), position, .. })
+                if position == at(11, 1, 12),
+        ));
+    }
+
+    // A Windows-style newline.
+    let error = Validator::new(&b"Hello world\r\n"[..])
+        .expect_line()
+        .unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "line 1, column 12 (byte 11): expected a newline ('\\n'), found '\\r'",
+    );
+    assert!(Validator::new(&b"Hello\rworld\n"[..])
+        .expect_line()
+        .is_err());
+
+    // Spaces within the line are fine.
+    assert_eq!(
+        Validator::new(&b" Hello  world\n"[..])
+            .expect_line()
+            .unwrap(),
+        b" Hello  world",
+    );
+}
+
+#[test]
+fn floating_point() {
+    let mut validator = Validator::new(&b"36.5 abc"[..]);
+    assert!((validator.expect_f64().unwrap() - 36.5).abs() < f64::EPSILON);
+    validator.expect_space().unwrap();
+    assert!(matches!(
+        validator.expect_f64(),
+        Err(ValidationError::Invalid { position, .. }) if position == at(5, 1, 6),
+    ));
+}
+
+#[test]
+fn out_of_range() {
+    let error = Validator::new(&b"1001\n"[..])
+        .expect_u32_in(1..=1000)
+        .unwrap_err();
+    assert!(matches!(
+        error,
+        ValidationError::OutOfRange { ref value, .. } if value == "1001",
+    ));
+    assert_eq!(
+        error.to_string(),
+        "line 1, column 1 (byte 0): 1001 is not between 1 and 1000",
+    );
+
+    // Too large for any `u32`.
+    assert!(matches!(
+        Validator::new(&b"99999999999"[..]).expect_u32_in(0..=u32::MAX),
+        Err(ValidationError::OutOfRange { .. }),
+    ));
+}
+
+#[test]
+fn not_a_number() {
+    for input in [&b"-1"[..], b"+1", b" 1", b""] {
+        assert!(matches!(
+            Validator::new(input).expect_u32_in(0..=u32::MAX),
+            Err(ValidationError::Expected { expected: "a digit", position, .. })
+                if position == Position::START,
+        ));
+    }
+
+    assert!(matches!(
+        Validator::new(&b"007"[..]).expect_u32_in(0..=u32::MAX),
+        Err(ValidationError::Invalid { .. }),
+    ));
+    assert_eq!(Validator::new(&b"0"[..]).expect_u32_in(0..=0).unwrap(), 0);
+}
+
+#[test]
+fn whitespace() {
+    // Two spaces.
+    let mut validator = Validator::new(&b"1  2"[..]);
+    validator.expect_u32_in(1..=1).unwrap();
+    validator.expect_space().unwrap();
+    assert!(matches!(
+        validator.expect_u32_in(2..=2),
+        Err(ValidationError::Expected { found: Some(b' '), position, .. })
+            if position == at(2, 1, 3),
+    ));
+
+    // A Windows-style newline.
+    let mut validator = Validator::new(&b"1\r\n"[..]);
+    validator.expect_u32_in(1..=1).unwrap();
+    let error = validator.expect_eoln().unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "line 1, column 2 (byte 1): expected a newline ('\\n'), found '\\r'",
+    );
+
+    // Trailing whitespace.
+    let mut validator = Validator::new(&b"1 \n"[..]);
+    validator.expect_u32_in(1..=1).unwrap();
+    assert!(validator.expect_eoln().is_err());
+}
+
+#[test]
+fn end_of_input() {
+    // A missing final newline.
+    let mut validator = Validator::new(&b"1"[..]);
+    validator.expect_u32_in(1..=1).unwrap();
+    assert_eq!(
+        validator.expect_eoln().unwrap_err().to_string(),
+        "line 1, column 2 (byte 1): expected a newline ('\\n'), found the end of the input",
+    );
+
+    // An extra empty line.
+    let mut validator = Validator::new(&b"1\n\n"[..]);
+    validator.expect_u32_in(1..=1).unwrap();
+    validator.expect_eoln().unwrap();
+    assert!(matches!(
+        validator.expect_eof(),
+        Err(ValidationError::Expected { found: Some(b'\n'), position, .. })
+            if position == at(2, 2, 1),
+    ));
+}
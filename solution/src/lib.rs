@@ -0,0 +1,69 @@
+use std::io::{self, prelude::*};
+
+#[cfg(feature = "stress")]
+mod stress;
+
+#[cfg(feature = "stress")]
+pub use stress::{stress, Cases, Generated, Inputs};
+
+/// The most test cases that the packages' `validate` functions accept and that `gen` writes.
+///
+/// The statements don't bound the number of test cases, so this is a limit of the tooling;
+/// the solutions themselves read any number of test cases.
+pub const MAX_TEST_CASES: u32 = 100_000;
+
+/// A solution to a problem, split into reading the input, solving and writing the answer.
+///
+/// Tools that work over any problem, such as test runners and benches,
+/// can use this instead of each package's `main_impl`.
+pub trait Solution {
+    /// The problem number, such as `"A5"`.
+    const NAME: &'static str;
+    /// The title of the problem and a summary of its input and output.
+    ///
+    /// Packages take this from the description in their `Cargo.toml`.
+    const DESCRIPTION: &'static str;
+
+    /// The parsed input of the problem.
+    type Input;
+    /// The answer to the problem, before it is written out.
+    type Output;
+
+    /// Reads the input of the problem.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the input is malformed or if any I/O error occured.
+    fn parse(input: impl BufRead) -> Self::Input;
+
+    /// Solves the problem.
+    fn solve(input: Self::Input) -> Self::Output;
+
+    /// Writes the answer to the problem in the required format.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the writer returns an error.
+    fn format(output: &Self::Output, writer: impl Write) -> io::Result<()>;
+
+    /// Reads the input from `input` and writes the answer to `output`, then flushes `output`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the input is malformed or if any I/O error occured,
+    /// including one that only shows up when a buffered `output` is flushed.
+    fn run(input: impl BufRead, mut output: impl Write) {
+        Self::format(&Self::solve(Self::parse(input)), &mut output)
+            .and_then(|()| output.flush())
+            .expect("An output error occured");
+    }
+}
+
+/// A solution with a slow but straightforward counterpart, which it can be checked against.
+pub trait BruteForce: Solution {
+    /// Solves the problem in the most obvious way, however slow.
+    ///
+    /// This should be simple enough to be trusted without any clever reasoning,
+    /// and only needs to handle small inputs.
+    fn brute_force(input: Self::Input) -> Self::Output;
+}
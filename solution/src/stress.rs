@@ -0,0 +1,113 @@
+use std::{fmt, fmt::Debug};
+
+use quickcheck::{Arbitrary, Gen, QuickCheck, TestResult};
+
+use crate::BruteForce;
+
+/// A kind of small random inputs of a problem, which [`stress`] tests a solution on.
+pub trait Inputs: 'static {
+    /// The generated values, which are converted into inputs of the problem.
+    ///
+    /// They are shrunk by their own [`Arbitrary::shrink`], keeping those that are still valid.
+    type Value: Arbitrary + Debug;
+
+    /// Generates a value, which should be small and satisfy the constraints of the problem.
+    fn generate(g: &mut Gen) -> Self::Value;
+
+    /// Returns whether a shrunk value still satisfies the constraints of the problem.
+    ///
+    /// By default, every value is valid.
+    fn is_valid(_value: &Self::Value) -> bool {
+        true
+    }
+}
+
+/// A value generated by `I`, which only shrinks into values that `I` considers valid.
+///
+/// This is what [`stress`] feeds to a solution,
+/// and may also be taken by other properties that are checked with [`quickcheck`].
+pub struct Generated<I: Inputs>(pub I::Value);
+
+impl<I: Inputs> Clone for Generated<I> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<I: Inputs> Debug for Generated<I> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<I: Inputs> Arbitrary for Generated<I> {
+    fn arbitrary(g: &mut Gen) -> Self {
+        Self(I::generate(g))
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new(self.0.shrink().filter(I::is_valid).map(Self))
+    }
+}
+
+/// Test cases generated as `T`, such as tuples, which convert one by one into those of a problem.
+///
+/// This lets [`Inputs`] generate the input of a problem that is a `Vec` of its own test case type.
+#[derive(Clone, Debug)]
+pub struct Cases<T>(pub Vec<T>);
+
+impl<T: Arbitrary> Arbitrary for Cases<T> {
+    fn arbitrary(g: &mut Gen) -> Self {
+        Self(Vec::arbitrary(g))
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new(self.0.shrink().map(Self))
+    }
+}
+
+impl<T: Into<U>, U> From<Cases<T>> for Vec<U> {
+    fn from(Cases(cases): Cases<T>) -> Self {
+        cases.into_iter().map(Into::into).collect()
+    }
+}
+
+/// Checks that the solution `S` gives the same answers as its brute force on `tests` random inputs.
+///
+/// The inputs are generated by `I`.
+///
+/// # Panics
+///
+/// Panics if an answer differs from that of the brute force.
+/// The input is shrunk first, so the message shows the smallest input that gives a different answer.
+pub fn stress<S, I>(tests: u64)
+where
+    S: BruteForce,
+    S::Output: PartialEq + Debug,
+    I: Inputs,
+    I::Value: Into<S::Input>,
+{
+    fn agrees<S, I>(Generated(value): Generated<I>) -> TestResult
+    where
+        S: BruteForce,
+        S::Output: PartialEq + Debug,
+        I: Inputs,
+        I::Value: Into<S::Input>,
+    {
+        let expected = S::brute_force(value.clone().into());
+        let found = S::solve(value.into());
+        if found == expected {
+            TestResult::passed()
+        } else {
+            TestResult::error(format!(
+                "{name}: the brute force gives {expected:?}, but the solution gives {found:?}",
+                name = S::NAME,
+            ))
+        }
+    }
+
+    QuickCheck::new()
+        .tests(tests)
+        .max_tests(tests * 10)
+        .quickcheck(agrees::<S, I> as fn(Generated<I>) -> TestResult);
+}
@@ -0,0 +1,196 @@
+use std::io::{self, prelude::*};
+
+/// An integer that can be written by [`WriteInt`].
+///
+/// This is implemented for all primitive integer types.
+pub trait Int: itoa::Integer {}
+
+macro_rules! impl_int {
+    ($($ty:ty)*) => {
+        $(impl Int for $ty {})*
+    };
+}
+
+impl_int! {
+    u8 u16 u32 u64 u128 usize
+    i8 i16 i32 i64 i128 isize
+}
+
+/// A block-buffered writer.
+///
+/// Unlike standard output, which is line-buffered,
+/// this only writes to the underlying writer when its buffer is full,
+/// so printing many short lines doesn't mean many writes.
+///
+/// Call [`flush`](Write::flush) when done writing, and handle its result:
+/// an error writing the last block of output only shows up there.
+/// The buffer is also flushed when the writer is dropped, but only as a fallback,
+/// since any error is ignored then.
+#[derive(Debug)]
+pub struct IntWriter<W: Write> {
+    buf: Vec<u8>,
+    writer: W,
+}
+
+impl<W: Write> IntWriter<W> {
+    /// The default size of the buffer, in bytes.
+    pub const DEFAULT_CAPACITY: usize = 64 * 1024;
+
+    /// Creates a new writer that writes to the given writer,
+    /// with a buffer of [`DEFAULT_CAPACITY`](Self::DEFAULT_CAPACITY) bytes.
+    #[must_use]
+    pub fn new(writer: W) -> Self {
+        Self::with_capacity(Self::DEFAULT_CAPACITY, writer)
+    }
+
+    /// Creates a new writer that writes to the given writer,
+    /// with a buffer of at least `capacity` bytes.
+    #[must_use]
+    pub fn with_capacity(capacity: usize, writer: W) -> Self {
+        Self {
+            buf: Vec::with_capacity(capacity),
+            writer,
+        }
+    }
+
+    /// Returns a reference to the underlying writer.
+    pub const fn get_ref(&self) -> &W {
+        &self.writer
+    }
+
+    /// Returns the bytes in the buffer that are not yet written to the underlying writer.
+    #[must_use]
+    pub fn buffer(&self) -> &[u8] {
+        &self.buf
+    }
+
+    /// Writes the entire buffer into the underlying writer.
+    fn flush_buf(&mut self) -> io::Result<()> {
+        // Clear the buffer even on error, so that nothing is written twice.
+        let result = self.writer.write_all(&self.buf);
+        self.buf.clear();
+        result
+    }
+}
+
+impl<W: Write> Write for IntWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if self.buf.len() + buf.len() > self.buf.capacity() {
+            self.flush_buf()?;
+        }
+
+        // Don't bother copying anything that is too large for the buffer anyway.
+        if buf.len() >= self.buf.capacity() {
+            self.writer.write(buf)
+        } else {
+            self.buf.extend_from_slice(buf);
+            Ok(buf.len())
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.flush_buf()?;
+        self.writer.flush()
+    }
+}
+
+impl<W: Write> Drop for IntWriter<W> {
+    fn drop(&mut self) {
+        // There's no way to report an error here; callers should have flushed already.
+        let _ = self.flush();
+    }
+}
+
+/// Methods for writing numbers and common kinds of answers into a writer.
+///
+/// None of the methods write a newline, except [`write_newline`](Self::write_newline).
+///
+/// These work with any writer, but should be used with a buffered one like [`IntWriter`],
+/// as each method may call [`write_all`](Write::write_all) several times.
+pub trait WriteInt: Write {
+    /// Writes an integer in decimal.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the underlying writer returns an error.
+    fn write_int<T: Int>(&mut self, n: T) -> io::Result<()> {
+        self.write_all(itoa::Buffer::new().format(n).as_bytes())
+    }
+
+    /// Writes a `u32` in decimal.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the underlying writer returns an error.
+    fn write_u32(&mut self, n: u32) -> io::Result<()> {
+        self.write_int(n)
+    }
+
+    /// Writes a `u64` in decimal.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the underlying writer returns an error.
+    fn write_u64(&mut self, n: u64) -> io::Result<()> {
+        self.write_int(n)
+    }
+
+    /// Writes an `i64` in decimal.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the underlying writer returns an error.
+    fn write_i64(&mut self, n: i64) -> io::Result<()> {
+        self.write_int(n)
+    }
+
+    /// Writes `YES` if `yes` is true, or `NO` otherwise.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the underlying writer returns an error.
+    fn write_yes_no(&mut self, yes: bool) -> io::Result<()> {
+        self.write_all(if yes { b"YES" } else { b"NO" })
+    }
+
+    /// Writes an integer, or `-1` if there is none.
+    ///
+    /// Many problems ask for `-1` when there is no answer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the underlying writer returns an error.
+    fn write_or_minus_one<T: Int>(&mut self, n: Option<T>) -> io::Result<()> {
+        match n {
+            Some(n) => self.write_int(n),
+            None => self.write_all(b"-1"),
+        }
+    }
+
+    /// Writes integers separated by single spaces.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the underlying writer returns an error.
+    fn write_row<T: Int>(&mut self, row: impl IntoIterator<Item = T>) -> io::Result<()> {
+        let mut row = row.into_iter();
+        if let Some(first) = row.next() {
+            self.write_int(first)?;
+        }
+        row.try_for_each(|n| {
+            self.write_all(b" ")?;
+            self.write_int(n)
+        })
+    }
+
+    /// Writes a newline (`\n`).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the underlying writer returns an error.
+    fn write_newline(&mut self) -> io::Result<()> {
+        self.write_all(b"\n")
+    }
+}
+
+impl<W: Write + ?Sized> WriteInt for W {}
@@ -0,0 +1,83 @@
+use std::io::prelude::*;
+
+use write_int::{IntWriter, WriteInt};
+
+#[test]
+fn numbers() {
+    let mut output = Vec::new();
+    output.write_u32(u32::MAX).unwrap();
+    output.write_newline().unwrap();
+    output.write_u64(0).unwrap();
+    output.write_newline().unwrap();
+    output.write_i64(i64::MIN).unwrap();
+    output.write_newline().unwrap();
+    output.write_int(-5_i8).unwrap();
+    assert_eq!(output, b"4294967295\n0\n-9223372036854775808\n-5",);
+}
+
+#[test]
+fn helpers() {
+    let mut output = Vec::new();
+    output.write_yes_no(true).unwrap();
+    output.write_all(b" ").unwrap();
+    output.write_yes_no(false).unwrap();
+    output.write_newline().unwrap();
+    output.write_or_minus_one(Some(3_u32)).unwrap();
+    output.write_or_minus_one(None::<u32>).unwrap();
+    output.write_newline().unwrap();
+    output.write_row([1_u32, 20, 300]).unwrap();
+    output.write_newline().unwrap();
+    output.write_row(Vec::<u32>::new()).unwrap();
+    assert_eq!(output, b"YES NO\n3-1\n1 20 300\n");
+}
+
+/// A writer that records every call to `write`.
+#[derive(Debug, Default)]
+struct Recorder(Vec<Vec<u8>>);
+
+impl Write for Recorder {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.push(buf.to_vec());
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn block_buffered() {
+    let mut writer = IntWriter::with_capacity(8, Recorder::default());
+
+    for n in 1..=4 {
+        writer.write_u32(n).unwrap();
+        writer.write_newline().unwrap();
+    }
+    // Nothing is written until the buffer is full.
+    assert!(writer.get_ref().0.is_empty());
+    assert_eq!(writer.buffer(), b"1\n2\n3\n4\n");
+
+    writer.write_u32(5).unwrap();
+    assert_eq!(writer.get_ref().0, [b"1\n2\n3\n4\n"]);
+
+    // Anything too large for the buffer is written directly.
+    writer.write_all(b"0123456789").unwrap();
+    assert_eq!(
+        writer.get_ref().0,
+        [&b"1\n2\n3\n4\n"[..], b"5", b"0123456789"]
+    );
+
+    writer.flush().unwrap();
+    assert!(writer.buffer().is_empty());
+}
+
+#[test]
+fn flush_on_drop() {
+    let mut output = Vec::new();
+    {
+        let mut writer = IntWriter::new(&mut output);
+        writer.write_u32(42).unwrap();
+    }
+    assert_eq!(output, b"42");
+}